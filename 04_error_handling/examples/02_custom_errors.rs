@@ -41,6 +41,24 @@ impl Error for AppError {
     }
 }
 
+impl AppError {
+    // Process exit code for this error, so scripts can tell failures apart.
+    // Codes follow the usual Unix convention of 0 for success and small
+    // positive numbers for distinct failure classes.
+    fn exit_code(&self) -> i32 {
+        match self {
+            // Invalid configuration or input
+            AppError::ValidationError(_) => 2,
+            // External service failures
+            AppError::DatabaseError { .. } => 3,
+            // Data that could not be processed
+            AppError::ParseError(_) => 4,
+            // Anything else
+            AppError::IoError(_) => 1,
+        }
+    }
+}
+
 // Implement From traits for automatic conversion
 impl From<io::Error> for AppError {
     fn from(err: io::Error) -> AppError {
@@ -151,7 +169,11 @@ fn main() {
     println!("\nAttempting to read config...");
     match read_config() {
         Ok(config) => println!("Config: {}", config),
-        Err(e) => println!("Error: {}", e),
+        Err(e) => {
+            println!("Error: {}", e);
+            println!("Exiting with code {}", e.exit_code());
+            std::process::exit(e.exit_code());
+        }
     }
 }
 
@@ -189,4 +211,20 @@ mod tests {
         let app_error: AppError = parse_error.into();
         assert!(matches!(app_error, AppError::ParseError(_)));
     }
+    
+    #[test]
+    fn test_exit_codes() {
+        let io_error = AppError::IoError(io::Error::new(io::ErrorKind::NotFound, "missing"));
+        let parse_error = AppError::ParseError("x".parse::<i32>().unwrap_err());
+        let validation_error = AppError::ValidationError("bad config".into());
+        let database_error = AppError::DatabaseError {
+            code: 1001,
+            message: "Connection failed".into(),
+        };
+        
+        assert_eq!(validation_error.exit_code(), 2);
+        assert_eq!(database_error.exit_code(), 3);
+        assert_eq!(parse_error.exit_code(), 4);
+        assert_eq!(io_error.exit_code(), 1);
+    }
 } 
\ No newline at end of file
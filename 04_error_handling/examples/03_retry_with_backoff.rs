@@ -0,0 +1,169 @@
+// Example: Retrying Fallible Operations with Exponential Backoff
+// This example demonstrates retrying transient errors while giving up on permanent ones
+
+use std::fmt;
+use std::thread;
+use std::time::Duration;
+
+// Errors a remote request can produce
+#[derive(Debug, PartialEq)]
+enum RequestError {
+    RateLimited,
+    Timeout,
+    NotFound,
+    BadRequest(String),
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RequestError::RateLimited => write!(f, "Rate limit exceeded"),
+            RequestError::Timeout => write!(f, "Request timed out"),
+            RequestError::NotFound => write!(f, "Resource not found"),
+            RequestError::BadRequest(msg) => write!(f, "Bad request: {}", msg),
+        }
+    }
+}
+
+impl RequestError {
+    // Only transient failures are worth another attempt
+    fn is_transient(&self) -> bool {
+        matches!(self, RequestError::RateLimited | RequestError::Timeout)
+    }
+}
+
+// Retries `op` up to `attempts` times in total, sleeping `base_delay * 2^n`
+// after the n-th failure. Returns the last error once attempts run out.
+fn retry_with_backoff<T, E, F>(attempts: u32, base_delay: Duration, op: F) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    retry_with_backoff_if(attempts, base_delay, |_| true, op)
+}
+
+// Like `retry_with_backoff`, but stops immediately when `should_retry`
+// returns false for an error (e.g. a 4xx that will never succeed).
+fn retry_with_backoff_if<T, E, F, P>(
+    attempts: u32,
+    base_delay: Duration,
+    mut should_retry: P,
+    mut op: F,
+) -> Result<T, E>
+where
+    F: FnMut() -> Result<T, E>,
+    P: FnMut(&E) -> bool,
+{
+    let attempts = attempts.max(1);
+    let mut attempt = 0;
+    
+    loop {
+        match op() {
+            Ok(value) => return Ok(value),
+            Err(e) => {
+                attempt += 1;
+                if attempt >= attempts || !should_retry(&e) {
+                    return Err(e);
+                }
+                
+                let factor = 2u32.checked_pow(attempt - 1).unwrap_or(u32::MAX);
+                thread::sleep(base_delay.saturating_mul(factor));
+            }
+        }
+    }
+}
+
+fn main() {
+    // Example 1: A transient failure that eventually succeeds
+    println!("Retrying a flaky request...");
+    let mut calls = 0;
+    let result = retry_with_backoff(5, Duration::from_millis(10), || {
+        calls += 1;
+        println!("  attempt {}", calls);
+        if calls < 3 {
+            Err(RequestError::Timeout)
+        } else {
+            Ok("payload")
+        }
+    });
+    println!("Result: {:?}", result);
+    
+    // Example 2: Giving up once attempts are exhausted
+    println!("\nRetrying a request that keeps getting rate limited...");
+    let result: Result<(), RequestError> =
+        retry_with_backoff(3, Duration::from_millis(10), || Err(RequestError::RateLimited));
+    match result {
+        Ok(_) => println!("Unexpected success"),
+        Err(e) => println!("Gave up: {}", e),
+    }
+    
+    // Example 3: Not retrying permanent errors
+    println!("\nRequesting a missing resource...");
+    let mut calls = 0;
+    let result: Result<(), RequestError> = retry_with_backoff_if(
+        5,
+        Duration::from_millis(10),
+        RequestError::is_transient,
+        || {
+            calls += 1;
+            Err(RequestError::NotFound)
+        },
+    );
+    println!("Result after {} call(s): {:?}", calls, result);
+    
+    let invalid = RequestError::BadRequest("missing field `name`".into());
+    println!("Would retry '{}'? {}", invalid, invalid.is_transient());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_succeeds_after_transient_failures() {
+        let mut calls = 0;
+        let result = retry_with_backoff(5, Duration::from_millis(1), || {
+            calls += 1;
+            if calls <= 2 {
+                Err(RequestError::Timeout)
+            } else {
+                Ok(42)
+            }
+        });
+        
+        assert_eq!(result, Ok(42));
+        assert_eq!(calls, 3);
+    }
+    
+    #[test]
+    fn test_returns_last_error_when_exhausted() {
+        let mut calls = 0;
+        let result: Result<(), RequestError> = retry_with_backoff(3, Duration::from_millis(1), || {
+            calls += 1;
+            if calls < 3 {
+                Err(RequestError::Timeout)
+            } else {
+                Err(RequestError::RateLimited)
+            }
+        });
+        
+        assert_eq!(result, Err(RequestError::RateLimited));
+        assert_eq!(calls, 3);
+    }
+    
+    #[test]
+    fn test_predicate_stops_on_permanent_error() {
+        let mut calls = 0;
+        let result: Result<(), RequestError> = retry_with_backoff_if(
+            5,
+            Duration::from_millis(1),
+            RequestError::is_transient,
+            || {
+                calls += 1;
+                Err(RequestError::NotFound)
+            },
+        );
+        
+        assert_eq!(result, Err(RequestError::NotFound));
+        assert_eq!(calls, 1);
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,128 @@
+// Example: Client-Side Rate Limiting with a Token Bucket
+// This example demonstrates throttling requests before the server has to reject them
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+// A bucket holds up to `capacity` tokens and refills continuously at
+// `refill_per_sec`. Each request spends one token.
+#[derive(Debug)]
+struct RateLimiter {
+    capacity: f64,
+    tokens: f64,
+    refill_per_sec: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    // Starts with a full bucket. Panics if `capacity` is zero or
+    // `refill_per_sec` is not positive, since no request could ever get a token.
+    fn new(capacity: u32, refill_per_sec: f64) -> Self {
+        assert!(capacity >= 1, "capacity must be at least 1");
+        assert!(refill_per_sec > 0.0, "refill rate must be positive");
+        RateLimiter {
+            capacity: capacity as f64,
+            tokens: capacity as f64,
+            refill_per_sec,
+            last_refill: Instant::now(),
+        }
+    }
+    
+    // Adds the tokens earned since the last refill, up to capacity
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+    }
+    
+    // Takes a token if one is available, without waiting
+    fn try_acquire(&mut self) -> bool {
+        self.try_acquire_at(Instant::now())
+    }
+    
+    fn try_acquire_at(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+    
+    // Sleeps until a token is available, then takes it
+    fn acquire_blocking(&mut self) {
+        while !self.try_acquire() {
+            let missing = 1.0 - self.tokens;
+            thread::sleep(Duration::from_secs_f64(missing / self.refill_per_sec));
+        }
+    }
+}
+
+fn main() {
+    // Example 1: Bursting until the bucket is empty
+    println!("Burst of requests against a bucket of 3:");
+    let mut limiter = RateLimiter::new(3, 10.0);
+    for i in 1..=5 {
+        if limiter.try_acquire() {
+            println!("  request {} sent", i);
+        } else {
+            println!("  request {} throttled", i);
+        }
+    }
+    
+    // Example 2: Blocking until tokens refill
+    println!("\nSending 5 requests at 10 per second:");
+    let mut limiter = RateLimiter::new(1, 10.0);
+    let start = Instant::now();
+    for i in 1..=5 {
+        limiter.acquire_blocking();
+        println!("  request {} sent after {:?}", i, start.elapsed());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_exhausted_bucket_refills_over_time() {
+        let mut limiter = RateLimiter::new(2, 4.0);
+        let start = limiter.last_refill;
+        
+        assert!(limiter.try_acquire_at(start));
+        assert!(limiter.try_acquire_at(start));
+        assert!(!limiter.try_acquire_at(start));
+        
+        // One token takes 250ms at 4 per second
+        assert!(!limiter.try_acquire_at(start + Duration::from_millis(200)));
+        assert!(limiter.try_acquire_at(start + Duration::from_millis(260)));
+        assert!(!limiter.try_acquire_at(start + Duration::from_millis(260)));
+    }
+    
+    #[test]
+    fn test_refill_is_capped_at_capacity() {
+        let mut limiter = RateLimiter::new(2, 100.0);
+        let later = limiter.last_refill + Duration::from_secs(10);
+        
+        assert!(limiter.try_acquire_at(later));
+        assert!(limiter.try_acquire_at(later));
+        assert!(!limiter.try_acquire_at(later));
+    }
+    
+    #[test]
+    fn test_acquire_blocking_waits() {
+        let mut limiter = RateLimiter::new(1, 50.0);
+        assert!(limiter.try_acquire());
+        
+        let start = Instant::now();
+        limiter.acquire_blocking();
+        assert!(start.elapsed() >= Duration::from_millis(15));
+    }
+    
+    #[test]
+    #[should_panic(expected = "capacity must be at least 1")]
+    fn test_zero_capacity_panics() {
+        RateLimiter::new(0, 10.0);
+    }
+} 
\ No newline at end of file
@@ -78,22 +78,9 @@ impl FileProcessor {
             line_number += 1;
             let line = line?;
             
-            if line.trim().is_empty() {
-                continue;
+            if let Some(number) = self.parse_line(&line, line_number)? {
+                numbers.push(number);
             }
-            
-            // Parse the number
-            let number = line
-                .trim()
-                .parse()
-                .map_err(|e| FileProcessError::ParseError {
-                    line: line_number,
-                    error: e,
-                })?;
-            
-            // Validate the number
-            self.validate_number(number, line_number)?;
-            numbers.push(number);
         }
         
         if numbers.is_empty() {
@@ -103,6 +90,44 @@ impl FileProcessor {
         }
     }
     
+    // Parse every non-empty line of `content`, collecting all parse and
+    // range errors instead of stopping at the first one
+    fn parse_numbers_collect_errors(&self, content: &str) -> Result<Vec<i32>, Vec<FileProcessError>> {
+        let mut numbers = Vec::new();
+        let mut errors = Vec::new();
+        
+        for (index, line) in content.lines().enumerate() {
+            match self.parse_line(line, index + 1) {
+                Ok(Some(number)) => numbers.push(number),
+                Ok(None) => {}
+                Err(e) => errors.push(e),
+            }
+        }
+        
+        if !errors.is_empty() {
+            Err(errors)
+        } else if numbers.is_empty() {
+            Err(vec![FileProcessError::EmptyFileError])
+        } else {
+            Ok(numbers)
+        }
+    }
+    
+    // Parse and validate a single line; blank lines give Ok(None)
+    fn parse_line(&self, line: &str, line_number: usize) -> Result<Option<i32>, FileProcessError> {
+        let line = line.trim();
+        if line.is_empty() {
+            return Ok(None);
+        }
+        
+        let number = line.parse().map_err(|e| FileProcessError::ParseError {
+            line: line_number,
+            error: e,
+        })?;
+        self.validate_number(number, line_number)?;
+        Ok(Some(number))
+    }
+    
     // Validate a single number
     fn validate_number(&self, number: i32, line: usize) -> Result<(), FileProcessError> {
         if number < self.min_value {
@@ -177,6 +202,19 @@ fn main() {
     process_file("invalid_numbers.txt");
     process_file("empty_file.txt");
     process_file("nonexistent_file.txt");
+    
+    // Reporting every problem at once
+    println!("Validating input with multiple problems:");
+    let processor = FileProcessor::new(String::new(), 0, 100);
+    match processor.parse_numbers_collect_errors("10\nabc\n250\n40\n") {
+        Ok(numbers) => println!("All valid: {:?}", numbers),
+        Err(errors) => {
+            println!("Found {} problem(s):", errors.len());
+            for error in errors {
+                println!("  {}", error);
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -269,4 +307,25 @@ mod tests {
         assert_eq!(stats.min, 10);
         assert_eq!(stats.max, 50);
     }
+    
+    #[test]
+    fn test_collect_all_errors() {
+        let processor = FileProcessor::new(String::new(), 0, 100);
+        
+        let errors = processor
+            .parse_numbers_collect_errors("10\nabc\n30\n\n500\n")
+            .unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(matches!(errors[0], FileProcessError::ParseError { line: 2, .. }));
+        assert!(matches!(errors[1], FileProcessError::ValidationError { line: 5, .. }));
+        
+        assert_eq!(
+            processor.parse_numbers_collect_errors("10\n20\n").unwrap(),
+            vec![10, 20]
+        );
+        assert!(matches!(
+            processor.parse_numbers_collect_errors("\n").unwrap_err()[..],
+            [FileProcessError::EmptyFileError]
+        ));
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,110 @@
+// Example: Fixed-Capacity Ring Buffer
+// This example demonstrates building a bounded collection on top of VecDeque
+
+use std::collections::VecDeque;
+
+// A buffer that keeps only the most recent `capacity` items
+#[derive(Debug)]
+struct RingBuffer<T> {
+    items: VecDeque<T>,
+    capacity: usize,
+}
+
+impl<T> RingBuffer<T> {
+    // Panics if `capacity` is zero, since such a buffer could hold nothing
+    fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "RingBuffer capacity must be greater than zero");
+        RingBuffer {
+            items: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+    
+    // Adds an item, returning the oldest one if it had to be evicted
+    fn push(&mut self, item: T) -> Option<T> {
+        let evicted = if self.items.len() == self.capacity {
+            self.items.pop_front()
+        } else {
+            None
+        };
+        self.items.push_back(item);
+        evicted
+    }
+    
+    // Iterates from the oldest to the newest item
+    fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+    
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+    
+    fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+    
+    fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+fn main() {
+    // Command history capped at three entries
+    println!("Command history:");
+    let mut history = RingBuffer::new(3);
+    println!("Empty? {}", history.is_empty());
+    
+    for command in ["ls", "cd src", "cargo build", "cargo test", "git status"] {
+        if let Some(dropped) = history.push(command.to_string()) {
+            println!("History full, dropped: {}", dropped);
+        }
+    }
+    
+    println!("Kept {} of {} slots:", history.len(), history.capacity());
+    for (i, command) in history.iter().enumerate() {
+        println!("  {}: {}", i + 1, command);
+    }
+    
+    // Rolling window of recent readings
+    println!("\nRecent temperature readings:");
+    let mut readings = RingBuffer::new(4);
+    for reading in [21.5, 22.0, 22.4, 23.1, 22.8, 22.2] {
+        readings.push(reading);
+        let average: f64 = readings.iter().sum::<f64>() / readings.len() as f64;
+        println!("  after {:.1}: average of last {} = {:.2}", reading, readings.len(), average);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_push_within_capacity() {
+        let mut buffer = RingBuffer::new(3);
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.push(1), None);
+        assert_eq!(buffer.push(2), None);
+        
+        assert_eq!(buffer.len(), 2);
+        assert_eq!(buffer.capacity(), 3);
+        assert_eq!(buffer.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    }
+    
+    #[test]
+    fn test_push_evicts_oldest_in_order() {
+        let mut buffer = RingBuffer::new(3);
+        let evicted: Vec<Option<i32>> = (1..=6).map(|i| buffer.push(i)).collect();
+        
+        assert_eq!(evicted, vec![None, None, None, Some(1), Some(2), Some(3)]);
+        assert_eq!(buffer.len(), 3);
+        assert_eq!(buffer.iter().cloned().collect::<Vec<_>>(), vec![4, 5, 6]);
+    }
+    
+    #[test]
+    #[should_panic]
+    fn test_zero_capacity_panics() {
+        let _buffer: RingBuffer<i32> = RingBuffer::new(0);
+    }
+} 
\ No newline at end of file
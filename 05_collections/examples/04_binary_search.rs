@@ -0,0 +1,161 @@
+// Example: Binary Search Helpers
+// This example demonstrates searching sorted slices and finding insertion points
+
+// Reusable search helpers. Every function here assumes the slice is already
+// sorted in ascending order; on unsorted input the result is meaningless.
+mod search {
+    use std::cmp::Ordering;
+    
+    // Works like `slice::binary_search_by`: `f` compares an element against the
+    // target. Returns Ok(index) of a matching element, or Err(index) where the
+    // target could be inserted to keep the slice sorted.
+    pub fn binary_search_by<T, F>(slice: &[T], mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        // Search the half-open range [low, high)
+        let mut low = 0;
+        let mut high = slice.len();
+        
+        while low < high {
+            let mid = low + (high - low) / 2;
+            match f(&slice[mid]) {
+                Ordering::Less => low = mid + 1,
+                Ordering::Greater => high = mid,
+                Ordering::Equal => return Ok(mid),
+            }
+        }
+        
+        Err(low)
+    }
+    
+    // First index where `value` could be inserted, i.e. before any equal elements
+    pub fn lower_bound<T: Ord>(slice: &[T], value: &T) -> usize {
+        partition_point(slice, |x| x < value)
+    }
+    
+    // Last index where `value` could be inserted, i.e. after any equal elements
+    pub fn upper_bound<T: Ord>(slice: &[T], value: &T) -> usize {
+        partition_point(slice, |x| x <= value)
+    }
+    
+    // Index of the first element for which `pred` is false
+    fn partition_point<T, P>(slice: &[T], mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut low = 0;
+        let mut high = slice.len();
+        
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if pred(&slice[mid]) {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        
+        low
+    }
+}
+
+use search::{binary_search_by, lower_bound, upper_bound};
+
+#[derive(Debug)]
+struct Product {
+    name: String,
+    price: u32,
+}
+
+fn main() {
+    // Searching a sorted list of numbers
+    println!("Searching numbers:");
+    let numbers = vec![2, 3, 5, 7, 11, 13, 17];
+    for target in [7, 1, 8, 20] {
+        match binary_search_by(&numbers, |x| x.cmp(&target)) {
+            Ok(index) => println!("  found {} at index {}", target, index),
+            Err(index) => println!("  {} not found, would insert at {}", target, index),
+        }
+    }
+    
+    // Keeping a vector sorted while inserting
+    println!("\nSorted insertion:");
+    let mut scores = vec![10, 20, 30];
+    for score in [25, 5, 20] {
+        let index = upper_bound(&scores, &score);
+        scores.insert(index, score);
+        println!("  inserted {} -> {:?}", score, scores);
+    }
+    
+    // Counting duplicates with lower and upper bounds
+    println!("\nCounting duplicates:");
+    let rolls = vec![1, 2, 2, 2, 3, 4, 4, 6];
+    for value in [2, 4, 5] {
+        let count = upper_bound(&rolls, &value) - lower_bound(&rolls, &value);
+        println!("  {} appears {} time(s)", value, count);
+    }
+    
+    // Searching structs by a field
+    println!("\nSearching products by price:");
+    let products = vec![
+        Product { name: "Pencil".to_string(), price: 1 },
+        Product { name: "Notebook".to_string(), price: 4 },
+        Product { name: "Backpack".to_string(), price: 35 },
+    ];
+    let budget = 4;
+    match binary_search_by(&products, |p| p.price.cmp(&budget)) {
+        Ok(index) => println!("  {} costs exactly {}", products[index].name, budget),
+        Err(_) => println!("  nothing costs exactly {}", budget),
+    }
+    println!("  products: {:?}", products);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const SORTED: [i32; 5] = [10, 20, 20, 20, 30];
+    
+    #[test]
+    fn test_binary_search_present() {
+        let result = binary_search_by(&SORTED, |x| x.cmp(&30));
+        assert_eq!(result, Ok(4));
+        
+        let result = binary_search_by(&SORTED, |x| x.cmp(&20));
+        assert!(matches!(result, Ok(1..=3)));
+    }
+    
+    #[test]
+    fn test_binary_search_absent() {
+        // Before, between, and after the existing elements
+        assert_eq!(binary_search_by(&SORTED, |x| x.cmp(&5)), Err(0));
+        assert_eq!(binary_search_by(&SORTED, |x| x.cmp(&25)), Err(4));
+        assert_eq!(binary_search_by(&SORTED, |x| x.cmp(&35)), Err(5));
+        assert_eq!(binary_search_by(&[] as &[i32], |x| x.cmp(&1)), Err(0));
+    }
+    
+    #[test]
+    fn test_matches_std_semantics() {
+        for target in 0..40 {
+            let ours = binary_search_by(&SORTED, |x| x.cmp(&target));
+            let std = SORTED.binary_search(&target);
+            assert_eq!(ours.is_ok(), std.is_ok());
+            if let (Err(a), Err(b)) = (ours, std) {
+                assert_eq!(a, b);
+            }
+        }
+    }
+    
+    #[test]
+    fn test_bounds() {
+        // Present: bounds surround the run of equal elements
+        assert_eq!(lower_bound(&SORTED, &20), 1);
+        assert_eq!(upper_bound(&SORTED, &20), 4);
+        
+        // Absent before, between, and after
+        assert_eq!((lower_bound(&SORTED, &5), upper_bound(&SORTED, &5)), (0, 0));
+        assert_eq!((lower_bound(&SORTED, &25), upper_bound(&SORTED, &25)), (4, 4));
+        assert_eq!((lower_bound(&SORTED, &35), upper_bound(&SORTED, &35)), (5, 5));
+    }
+} 
\ No newline at end of file
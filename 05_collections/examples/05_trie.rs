@@ -0,0 +1,126 @@
+// Example: Trie for Prefix Search
+// This example demonstrates a recursive tree of HashMaps for autocomplete-style lookups
+
+use std::collections::HashMap;
+
+// A single node: one child per next character, plus a marker for complete words
+#[derive(Debug, Default)]
+struct Node {
+    children: HashMap<char, Node>,
+    is_word: bool,
+}
+
+#[derive(Debug, Default)]
+struct Trie {
+    root: Node,
+}
+
+impl Trie {
+    fn new() -> Self {
+        Trie::default()
+    }
+    
+    fn insert(&mut self, word: &str) {
+        let mut node = &mut self.root;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.is_word = true;
+    }
+    
+    fn contains(&self, word: &str) -> bool {
+        self.find_node(word).is_some_and(|node| node.is_word)
+    }
+    
+    // Returns every stored word starting with `prefix`, in sorted order
+    fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        if let Some(node) = self.find_node(prefix) {
+            let mut current = prefix.to_string();
+            collect_words(node, &mut current, &mut words);
+        }
+        words.sort();
+        words
+    }
+    
+    // Walks down the tree following `prefix`, if every character is present
+    fn find_node(&self, prefix: &str) -> Option<&Node> {
+        let mut node = &self.root;
+        for c in prefix.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+}
+
+// Depth-first walk that pushes and pops characters on a shared buffer
+fn collect_words(node: &Node, current: &mut String, words: &mut Vec<String>) {
+    if node.is_word {
+        words.push(current.clone());
+    }
+    for (&c, child) in &node.children {
+        current.push(c);
+        collect_words(child, current, words);
+        current.pop();
+    }
+}
+
+fn main() {
+    // Building the trie
+    println!("Building a product-name trie:");
+    let mut trie = Trie::new();
+    let products = ["apple", "app", "apricot", "banana", "bandana", "band"];
+    for product in products.iter() {
+        trie.insert(product);
+    }
+    println!("Inserted: {:?}", products);
+    
+    // Exact lookups
+    println!("\nExact lookups:");
+    for word in ["app", "appl", "band", "cherry"] {
+        println!("  contains '{}'? {}", word, trie.contains(word));
+    }
+    
+    // Prefix lookups for autocomplete
+    println!("\nAutocomplete:");
+    for prefix in ["ap", "app", "ban", "c", ""] {
+        println!("  '{}' -> {:?}", prefix, trie.words_with_prefix(prefix));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn sample_trie() -> Trie {
+        let mut trie = Trie::new();
+        trie.insert("apple");
+        trie.insert("app");
+        trie.insert("apricot");
+        trie
+    }
+    
+    #[test]
+    fn test_contains() {
+        let trie = sample_trie();
+        assert!(trie.contains("app"));
+        assert!(trie.contains("apple"));
+        assert!(!trie.contains("ap"));
+        assert!(!trie.contains("apples"));
+    }
+    
+    #[test]
+    fn test_words_with_prefix() {
+        let trie = sample_trie();
+        assert_eq!(trie.words_with_prefix("ap"), vec!["app", "apple", "apricot"]);
+        assert_eq!(trie.words_with_prefix("app"), vec!["app", "apple"]);
+        assert!(trie.words_with_prefix("b").is_empty());
+    }
+    
+    #[test]
+    fn test_duplicate_insert() {
+        let mut trie = sample_trie();
+        trie.insert("app");
+        assert_eq!(trie.words_with_prefix("").len(), 3);
+    }
+} 
\ No newline at end of file
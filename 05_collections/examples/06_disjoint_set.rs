@@ -0,0 +1,135 @@
+// Example: Disjoint Set (Union-Find)
+// This example demonstrates grouping elements into connected sets using two parallel vectors
+
+// Tracks which group each id in 0..n belongs to
+#[derive(Debug)]
+struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    groups: usize,
+}
+
+impl DisjointSet {
+    // Every id starts out in its own group
+    fn new(n: usize) -> Self {
+        DisjointSet {
+            parent: (0..n).collect(),
+            rank: vec![0; n],
+            groups: n,
+        }
+    }
+    
+    // Returns the representative of `x`'s group, pointing every visited node
+    // straight at the root so later lookups are shorter (path compression).
+    // Panics if `x` is out of range.
+    fn find(&mut self, x: usize) -> usize {
+        let parent = self.parent[x];
+        if parent == x {
+            return x;
+        }
+        let root = self.find(parent);
+        self.parent[x] = root;
+        root
+    }
+    
+    // Merges the groups containing `a` and `b`, attaching the shallower tree
+    // under the deeper one (union by rank)
+    fn union(&mut self, a: usize, b: usize) {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a == root_b {
+            return;
+        }
+        
+        if self.rank[root_a] < self.rank[root_b] {
+            self.parent[root_a] = root_b;
+        } else if self.rank[root_a] > self.rank[root_b] {
+            self.parent[root_b] = root_a;
+        } else {
+            self.parent[root_b] = root_a;
+            self.rank[root_a] += 1;
+        }
+        self.groups -= 1;
+    }
+    
+    fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+    
+    // Number of disjoint groups
+    fn count(&self) -> usize {
+        self.groups
+    }
+}
+
+fn main() {
+    // Friend circles: people are ids 0..6
+    println!("Friend circles:");
+    let names = ["Alice", "Bob", "Carol", "Dave", "Eve", "Frank"];
+    let friendships = [(0, 1), (1, 2), (3, 4)];
+    
+    let mut circles = DisjointSet::new(names.len());
+    println!("Groups before: {}", circles.count());
+    for &(a, b) in friendships.iter() {
+        circles.union(a, b);
+        println!("  {} befriends {}", names[a], names[b]);
+    }
+    println!("Groups after: {}", circles.count());
+    
+    // Connectivity queries
+    println!("\nConnectivity:");
+    for &(a, b) in [(0, 2), (0, 3), (3, 4), (5, 0)].iter() {
+        println!(
+            "  {} and {} connected? {}",
+            names[a],
+            names[b],
+            circles.connected(a, b)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_new_has_singleton_groups() {
+        let mut set = DisjointSet::new(4);
+        assert_eq!(set.count(), 4);
+        assert!(!set.connected(0, 1));
+        assert!(set.connected(2, 2));
+    }
+    
+    #[test]
+    fn test_union_and_count() {
+        let mut set = DisjointSet::new(6);
+        set.union(0, 1);
+        set.union(2, 3);
+        assert_eq!(set.count(), 4);
+        
+        set.union(1, 3);
+        assert_eq!(set.count(), 3);
+        assert!(set.connected(0, 2));
+        assert!(!set.connected(0, 4));
+        
+        // Unioning already-connected ids changes nothing
+        set.union(0, 3);
+        assert_eq!(set.count(), 3);
+    }
+    
+    #[test]
+    fn test_long_chain_compresses() {
+        let mut set = DisjointSet::new(100);
+        for i in 1..100 {
+            set.union(i - 1, i);
+        }
+        assert_eq!(set.count(), 1);
+        
+        // After a find on every id, each one points directly at the root
+        let root = set.find(0);
+        for i in 0..100 {
+            set.find(i);
+        }
+        assert!(set.parent.iter().all(|&p| p == root));
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,84 @@
+// Example: Set Operations over Slices
+// This example demonstrates reusable union/intersection/difference helpers built on BTreeSet
+
+// Every function accepts plain slices (duplicates and any order allowed) and
+// returns an owned, deduplicated Vec in ascending order.
+mod set_ops {
+    use std::collections::BTreeSet;
+    
+    fn to_set<T: Ord + Clone>(items: &[T]) -> BTreeSet<T> {
+        items.iter().cloned().collect()
+    }
+    
+    // Items found in either slice
+    pub fn union<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+        to_set(a).union(&to_set(b)).cloned().collect()
+    }
+    
+    // Items found in both slices
+    pub fn intersection<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+        to_set(a).intersection(&to_set(b)).cloned().collect()
+    }
+    
+    // Items found in `a` but not in `b`
+    pub fn difference<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+        to_set(a).difference(&to_set(b)).cloned().collect()
+    }
+    
+    // Items found in exactly one of the slices
+    pub fn symmetric_difference<T: Ord + Clone>(a: &[T], b: &[T]) -> Vec<T> {
+        to_set(a).symmetric_difference(&to_set(b)).cloned().collect()
+    }
+}
+
+use set_ops::{difference, intersection, symmetric_difference, union};
+
+fn main() {
+    // Numeric sets
+    println!("Number sets:");
+    let a = [1, 3, 5, 7, 9];
+    let b = [1, 2, 5, 8, 9];
+    println!("  a = {:?}", a);
+    println!("  b = {:?}", b);
+    println!("  union:                {:?}", union(&a, &b));
+    println!("  intersection:         {:?}", intersection(&a, &b));
+    println!("  a - b:                {:?}", difference(&a, &b));
+    println!("  symmetric difference: {:?}", symmetric_difference(&a, &b));
+    
+    // Comparing skill lists with duplicates and mixed order
+    println!("\nSkills:");
+    let alice = ["rust", "sql", "python", "rust"];
+    let bob = ["go", "rust", "docker"];
+    println!("  shared:      {:?}", intersection(&alice, &bob));
+    println!("  only Alice:  {:?}", difference(&alice, &bob));
+    println!("  all skills:  {:?}", union(&alice, &bob));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const A: [i32; 5] = [1, 3, 5, 7, 9];
+    const B: [i32; 5] = [1, 2, 5, 8, 9];
+    
+    #[test]
+    fn test_union_and_intersection() {
+        assert_eq!(union(&A, &B), vec![1, 2, 3, 5, 7, 8, 9]);
+        assert_eq!(intersection(&A, &B), vec![1, 5, 9]);
+    }
+    
+    #[test]
+    fn test_difference() {
+        assert_eq!(difference(&A, &B), vec![3, 7]);
+        assert_eq!(difference(&B, &A), vec![2, 8]);
+        assert_eq!(symmetric_difference(&A, &B), vec![2, 3, 7, 8]);
+    }
+    
+    #[test]
+    fn test_dedups_and_sorts() {
+        let a = ["pear", "apple", "pear"];
+        let b: [&str; 0] = [];
+        assert_eq!(union(&a, &b), vec!["apple", "pear"]);
+        assert!(intersection(&a, &b).is_empty());
+    }
+} 
\ No newline at end of file
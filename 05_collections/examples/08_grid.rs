@@ -0,0 +1,122 @@
+// Example: Dimension-Checked 2D Grid
+// This example demonstrates storing 2D data in a flat Vec with row-major indexing
+
+// A fixed-size grid; cell (row, col) lives at index row * cols + col
+#[derive(Debug, Clone, PartialEq)]
+struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    fn new(rows: usize, cols: usize, default: T) -> Self {
+        Grid {
+            rows,
+            cols,
+            cells: vec![default; rows * cols],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    fn rows(&self) -> usize {
+        self.rows
+    }
+    
+    fn cols(&self) -> usize {
+        self.cols
+    }
+    
+    // Flat index of a cell, or None when either coordinate is out of bounds
+    fn index(&self, row: usize, col: usize) -> Option<usize> {
+        if row < self.rows && col < self.cols {
+            Some(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+    
+    fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.index(row, col).map(|i| &self.cells[i])
+    }
+    
+    fn set(&mut self, row: usize, col: usize, value: T) -> Result<(), &'static str> {
+        let i = self.index(row, col).ok_or("cell out of bounds")?;
+        self.cells[i] = value;
+        Ok(())
+    }
+    
+    // A whole row as a slice, thanks to the row-major layout
+    fn row(&self, r: usize) -> Option<&[T]> {
+        if r < self.rows {
+            Some(&self.cells[r * self.cols..(r + 1) * self.cols])
+        } else {
+            None
+        }
+    }
+}
+
+fn main() {
+    // Drawing a shape into a character grid
+    println!("Drawing a diagonal:");
+    let mut canvas = Grid::new(5, 5, '.');
+    for i in 0..canvas.rows() {
+        canvas.set(i, i, '#').unwrap();
+    }
+    for r in 0..canvas.rows() {
+        let line: String = canvas.row(r).unwrap().iter().collect();
+        println!("  {}", line);
+    }
+    
+    // Out-of-bounds access is reported instead of panicking
+    println!("\nBounds checking:");
+    println!("  get(2, 2) = {:?}", canvas.get(2, 2));
+    println!("  get(5, 0) = {:?}", canvas.get(5, 0));
+    match canvas.set(0, 9, '#') {
+        Ok(()) => println!("  set(0, 9) succeeded"),
+        Err(e) => println!("  set(0, 9) failed: {}", e),
+    }
+    
+    // A small numeric matrix
+    println!("\nMultiplication table:");
+    let mut table = Grid::new(3, 4, 0);
+    for r in 0..table.rows() {
+        for c in 0..table.cols() {
+            table.set(r, c, (r + 1) * (c + 1)).unwrap();
+        }
+    }
+    for r in 0..table.rows() {
+        println!("  {:?}", table.row(r).unwrap());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_in_bounds_access() {
+        let mut grid = Grid::new(2, 3, 0);
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid.get(1, 2), Some(&0));
+        
+        grid.set(1, 2, 7).unwrap();
+        grid.set(0, 0, 1).unwrap();
+        assert_eq!(grid.get(1, 2), Some(&7));
+        assert_eq!(grid.row(0), Some(&[1, 0, 0][..]));
+        assert_eq!(grid.row(1), Some(&[0, 0, 7][..]));
+    }
+    
+    #[test]
+    fn test_out_of_bounds_access() {
+        let mut grid = Grid::new(2, 3, 'x');
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.row(2), None);
+        
+        assert!(grid.set(0, 3, 'y').is_err());
+        assert!(grid.set(2, 0, 'y').is_err());
+        assert_eq!(grid, Grid::new(2, 3, 'x'));
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,128 @@
+// Example: Sorting and Selection Helpers
+// This example demonstrates descending key sorts and quickselect for order statistics
+
+// Reusable helpers so callers don't have to hand-write comparison closures
+mod sorting {
+    use std::cmp::{Ordering, Reverse};
+    
+    // Sorts by the extracted key, highest first. The sort is stable, so items
+    // with equal keys keep their original relative order.
+    pub fn sort_by_key_desc<T, K: Ord>(items: &mut [T], key: impl Fn(&T) -> K) {
+        items.sort_by_key(|item| Reverse(key(item)));
+    }
+    
+    // Returns the k-th smallest item (k = 0 is the minimum), or None when k is
+    // out of bounds. Uses quickselect: only the part of the data that can
+    // still contain the answer is partitioned, giving O(n) on average instead
+    // of the O(n log n) of a full sort. The input slice is left untouched.
+    pub fn kth_smallest<T: Ord + Clone>(items: &[T], k: usize) -> Option<T> {
+        if k >= items.len() {
+            return None;
+        }
+        
+        let mut data = items.to_vec();
+        // The answer always lies in data[low..high]
+        let mut low = 0;
+        let mut high = data.len();
+        
+        loop {
+            let pivot = data[low + (high - low) / 2].clone();
+            
+            // Three-way partition of data[low..high] into
+            // [less than pivot | equal to pivot | greater than pivot]
+            let mut lt = low;
+            let mut i = low;
+            let mut gt = high;
+            while i < gt {
+                match data[i].cmp(&pivot) {
+                    Ordering::Less => {
+                        data.swap(lt, i);
+                        lt += 1;
+                        i += 1;
+                    }
+                    Ordering::Greater => {
+                        gt -= 1;
+                        data.swap(i, gt);
+                    }
+                    Ordering::Equal => i += 1,
+                }
+            }
+            
+            if k < lt {
+                high = lt;
+            } else if k >= gt {
+                low = gt;
+            } else {
+                return Some(pivot);
+            }
+        }
+    }
+}
+
+use sorting::{kth_smallest, sort_by_key_desc};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Player {
+    name: String,
+    score: u32,
+}
+
+fn main() {
+    // Leaderboard, highest score first
+    println!("Leaderboard:");
+    let mut players = vec![
+        Player { name: "Alice".to_string(), score: 120 },
+        Player { name: "Bob".to_string(), score: 340 },
+        Player { name: "Carol".to_string(), score: 120 },
+        Player { name: "Dave".to_string(), score: 275 },
+    ];
+    sort_by_key_desc(&mut players, |p| p.score);
+    for (rank, player) in players.iter().enumerate() {
+        println!("  {}. {:<6} {}", rank + 1, player.name, player.score);
+    }
+    
+    // Order statistics without sorting everything
+    println!("\nOrder statistics:");
+    let latencies = [87, 12, 45, 230, 33, 61, 19, 150, 72, 28];
+    println!("  data:    {:?}", latencies);
+    println!("  minimum: {:?}", kth_smallest(&latencies, 0));
+    println!("  median:  {:?}", kth_smallest(&latencies, latencies.len() / 2));
+    println!("  maximum: {:?}", kth_smallest(&latencies, latencies.len() - 1));
+    println!("  k = 10:  {:?}", kth_smallest(&latencies, 10));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_kth_smallest_matches_sorted_order() {
+        let data = [9, 4, 7, 4, 1, 8, 4, 2, 6, 3];
+        let mut sorted = data.to_vec();
+        sorted.sort();
+        
+        for (k, expected) in sorted.iter().enumerate() {
+            assert_eq!(kth_smallest(&data, k), Some(*expected));
+        }
+        assert_eq!(kth_smallest(&data, data.len()), None);
+        assert_eq!(kth_smallest::<i32>(&[], 0), None);
+        
+        let words = ["pear", "apple", "fig"];
+        assert_eq!(kth_smallest(&words, 1), Some("fig"));
+    }
+    
+    #[test]
+    fn test_sort_by_key_desc() {
+        let mut players = vec![
+            Player { name: "a".to_string(), score: 10 },
+            Player { name: "b".to_string(), score: 30 },
+            Player { name: "c".to_string(), score: 10 },
+            Player { name: "d".to_string(), score: 20 },
+        ];
+        sort_by_key_desc(&mut players, |p| p.score);
+        
+        let order: Vec<&str> = players.iter().map(|p| p.name.as_str()).collect();
+        // Ties keep their original order
+        assert_eq!(order, vec!["b", "d", "a", "c"]);
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,73 @@
+// Example: Finding a Pair with a Given Sum
+// This example demonstrates trading memory for speed with a HashMap of seen values
+
+use std::collections::HashMap;
+
+// Returns the indices (i, j) with i < j of two different elements adding up to
+// `target`, or None when no such pair exists. Each value is looked up before it
+// is recorded, so an element is never paired with itself; equal values at two
+// different positions still count. Runs in O(n) time and O(n) space.
+fn find_pair_with_sum(nums: &[i64], target: i64) -> Option<(usize, usize)> {
+    // value -> index of its first occurrence
+    let mut seen: HashMap<i64, usize> = HashMap::new();
+    
+    for (j, &value) in nums.iter().enumerate() {
+        // checked_sub avoids overflow near i64::MIN / i64::MAX; a complement
+        // that doesn't fit in i64 can't be in the slice anyway
+        if let Some(complement) = target.checked_sub(value) {
+            if let Some(&i) = seen.get(&complement) {
+                return Some((i, j));
+            }
+        }
+        seen.entry(value).or_insert(j);
+    }
+    
+    None
+}
+
+fn main() {
+    let prices = [12, 45, 7, 30, 18, 25];
+    println!("Prices: {:?}", prices);
+    
+    for budget in [55, 37, 100] {
+        match find_pair_with_sum(&prices, budget) {
+            Some((i, j)) => println!(
+                "  budget {}: items {} and {} ({} + {})",
+                budget, i, j, prices[i], prices[j]
+            ),
+            None => println!("  budget {}: no pair fits exactly", budget),
+        }
+    }
+    
+    // The same value at two positions is a valid pair
+    println!("\nDuplicates: {:?}", find_pair_with_sum(&[3, 3], 6));
+    // ...but a single element never pairs with itself
+    println!("Single 3:   {:?}", find_pair_with_sum(&[3, 1], 6));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_pair_found() {
+        assert_eq!(find_pair_with_sum(&[2, 7, 11, 15], 9), Some((0, 1)));
+        assert_eq!(find_pair_with_sum(&[-4, 10, 3, 8], 4), Some((0, 3)));
+        assert_eq!(find_pair_with_sum(&[i64::MAX - 1, -1, 1], i64::MAX), Some((0, 2)));
+    }
+    
+    #[test]
+    fn test_no_pair() {
+        assert_eq!(find_pair_with_sum(&[1, 2, 4], 8), None);
+        assert_eq!(find_pair_with_sum(&[5], 10), None);
+        assert_eq!(find_pair_with_sum(&[], 0), None);
+        assert_eq!(find_pair_with_sum(&[i64::MIN, -1], i64::MAX), None);
+    }
+    
+    #[test]
+    fn test_duplicates_form_a_pair() {
+        assert_eq!(find_pair_with_sum(&[3, 3], 6), Some((0, 1)));
+        assert_eq!(find_pair_with_sum(&[1, 3, 5, 3], 6), Some((0, 2)));
+        assert_eq!(find_pair_with_sum(&[3, 4], 6), None);
+    }
+} 
\ No newline at end of file
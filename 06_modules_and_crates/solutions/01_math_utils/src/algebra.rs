@@ -0,0 +1,199 @@
+//! Algebra module
+//!
+//! This module provides polynomial operations over `f64` coefficients.
+//!
+//! # Examples
+//!
+//! ```
+//! use math_utils::algebra::Polynomial;
+//!
+//! // 1 + 2x + 3x²
+//! let p = Polynomial::new(vec![1.0, 2.0, 3.0]);
+//! assert_eq!(p.evaluate(2.0), 17.0);
+//! assert_eq!(p.derivative(), Polynomial::new(vec![2.0, 6.0]));
+//! ```
+
+use crate::{MathError, MathResult};
+
+/// A polynomial stored as coefficients, where index `i` holds the
+/// coefficient of `x^i`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polynomial {
+    coefficients: Vec<f64>,
+}
+
+impl Polynomial {
+    /// Creates a new polynomial, trimming trailing zero coefficients
+    pub fn new(mut coefficients: Vec<f64>) -> Self {
+        while coefficients.last() == Some(&0.0) {
+            coefficients.pop();
+        }
+        Polynomial { coefficients }
+    }
+    
+    /// Returns the coefficients, lowest power first
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+    
+    /// Returns the highest power with a non-zero coefficient
+    ///
+    /// The zero polynomial reports a degree of 0.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+    
+    /// Evaluates the polynomial at `x` using Horner's method
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0.0, |acc, &c| acc * x + c)
+    }
+    
+    /// Calculates the derivative
+    pub fn derivative(&self) -> Polynomial {
+        Polynomial::new(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(power, &c)| c * power as f64)
+                .collect(),
+        )
+    }
+    
+    /// Adds another polynomial
+    pub fn add(&self, other: &Polynomial) -> Polynomial {
+        let len = self.coefficients.len().max(other.coefficients.len());
+        let coefficient = |p: &Polynomial, i: usize| p.coefficients.get(i).copied().unwrap_or(0.0);
+        Polynomial::new((0..len).map(|i| coefficient(self, i) + coefficient(other, i)).collect())
+    }
+    
+    /// Multiplies by another polynomial
+    pub fn mul(&self, other: &Polynomial) -> Polynomial {
+        if self.coefficients.is_empty() || other.coefficients.is_empty() {
+            return Polynomial::new(Vec::new());
+        }
+        
+        let mut product = vec![0.0; self.coefficients.len() + other.coefficients.len() - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in other.coefficients.iter().enumerate() {
+                product[i + j] += a * b;
+            }
+        }
+        Polynomial::new(product)
+    }
+    
+    /// Finds a root near `initial_guess` using the Newton-Raphson method
+    ///
+    /// Only one root is returned: whichever the iteration converges to from
+    /// the starting point. Use several guesses to look for others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_utils::algebra::Polynomial;
+    /// // x² - 2
+    /// let p = Polynomial::new(vec![-2.0, 0.0, 1.0]);
+    /// let root = p.find_root(1.4, 1e-12, 50).unwrap();
+    /// assert!((root - 2.0_f64.sqrt()).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::InvalidInput` if the derivative is zero at an iterate
+    /// Returns `MathError::OutOfRange` if no root is found within `max_iterations`
+    pub fn find_root(&self, initial_guess: f64, tolerance: f64, max_iterations: usize) -> MathResult<f64> {
+        let derivative = self.derivative();
+        let mut x = initial_guess;
+        
+        for _ in 0..max_iterations {
+            let value = self.evaluate(x);
+            if value.abs() <= tolerance {
+                return Ok(x);
+            }
+            
+            let slope = derivative.evaluate(x);
+            if slope == 0.0 {
+                return Err(MathError::InvalidInput(format!("derivative is zero at x = {}", x)));
+            }
+            
+            let next = x - value / slope;
+            if (next - x).abs() <= tolerance {
+                return Ok(next);
+            }
+            x = next;
+        }
+        
+        Err(MathError::OutOfRange(format!(
+            "no root found within {} iterations",
+            max_iterations
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    
+    #[test]
+    fn test_new_trims_trailing_zeros() {
+        let p = Polynomial::new(vec![1.0, 0.0, 2.0, 0.0, 0.0]);
+        assert_eq!(p.coefficients(), &[1.0, 0.0, 2.0]);
+        assert_eq!(p.degree(), 2);
+        
+        let zero = Polynomial::new(vec![0.0, 0.0]);
+        assert!(zero.coefficients().is_empty());
+        assert_eq!(zero.degree(), 0);
+        assert_eq!(zero.evaluate(3.0), 0.0);
+    }
+    
+    #[test]
+    fn test_evaluate_and_derivative() {
+        let p = Polynomial::new(vec![1.0, 2.0, 3.0]);
+        assert_relative_eq!(p.evaluate(2.0), 17.0);
+        assert_relative_eq!(p.evaluate(-1.0), 2.0);
+        
+        assert_eq!(p.derivative(), Polynomial::new(vec![2.0, 6.0]));
+        assert_eq!(p.derivative().derivative(), Polynomial::new(vec![6.0]));
+        assert_eq!(Polynomial::new(vec![5.0]).derivative().degree(), 0);
+    }
+    
+    #[test]
+    fn test_add_and_mul() {
+        let a = Polynomial::new(vec![1.0, 1.0]);
+        let b = Polynomial::new(vec![-1.0, 1.0]);
+        
+        assert_eq!(a.add(&b), Polynomial::new(vec![0.0, 2.0]));
+        // (1 + x)(-1 + x) = -1 + x²
+        assert_eq!(a.mul(&b), Polynomial::new(vec![-1.0, 0.0, 1.0]));
+        
+        // Cancelling leading terms lowers the degree
+        let c = Polynomial::new(vec![0.0, 0.0, -1.0]);
+        assert_eq!(a.mul(&b).add(&c).degree(), 0);
+        assert_eq!(a.mul(&Polynomial::new(vec![])), Polynomial::new(vec![]));
+    }
+    
+    #[test]
+    fn test_find_root() {
+        let p = Polynomial::new(vec![-2.0, 0.0, 1.0]);
+        assert_relative_eq!(p.find_root(1.4, 1e-12, 50).unwrap(), 2.0_f64.sqrt(), epsilon = 1e-12);
+        assert_relative_eq!(p.find_root(-3.0, 1e-12, 50).unwrap(), -(2.0_f64.sqrt()), epsilon = 1e-12);
+        
+        // Already on a root
+        assert_eq!(Polynomial::new(vec![0.0, 1.0]).find_root(0.0, 1e-12, 10).unwrap(), 0.0);
+    }
+    
+    #[test]
+    fn test_find_root_errors() {
+        // Flat at the guess: x² - 2 has zero slope at x = 0
+        let p = Polynomial::new(vec![-2.0, 0.0, 1.0]);
+        assert!(matches!(p.find_root(0.0, 1e-12, 50), Err(MathError::InvalidInput(_))));
+        
+        // x² + 1 has no real roots, so the iteration never settles
+        let q = Polynomial::new(vec![1.0, 0.0, 1.0]);
+        assert!(matches!(q.find_root(0.5, 1e-12, 100), Err(MathError::OutOfRange(_))));
+    }
+} 
\ No newline at end of file
@@ -3,6 +3,8 @@
 //! This module provides basic arithmetic operations like GCD, LCM,
 //! and other number theory functions.
 
+use std::fmt;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
 use crate::{MathError, MathResult, Number};
 
 /// Calculates the Greatest Common Divisor (GCD) of two numbers
@@ -46,6 +48,211 @@ where
     }
 }
 
+/// Calculates the Least Common Multiple (LCM) of two `u64` values, guarding
+/// against overflow
+///
+/// Divides by the GCD before multiplying to keep the intermediate value small.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::checked_lcm;
+/// assert_eq!(checked_lcm(4, 6).unwrap(), 12);
+/// assert_eq!(checked_lcm(0, 5).unwrap(), 0);
+/// assert!(checked_lcm(u64::MAX, u64::MAX - 1).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::OutOfRange` if the result would overflow `u64`
+pub fn checked_lcm(a: u64, b: u64) -> MathResult<u64> {
+    if a == 0 || b == 0 {
+        return Ok(0);
+    }
+    
+    (a / gcd(a, b))
+        .checked_mul(b)
+        .ok_or_else(|| MathError::OutOfRange("lcm overflow".to_string()))
+}
+
+/// Adds up a sequence of numbers
+///
+/// Returns zero for an empty slice. Integer overflow behaves like the `+`
+/// operator; use [`checked_sum`] to detect it.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::sum;
+/// assert_eq!(sum(&[1, 2, 3]), 6);
+/// assert_eq!(sum::<f64>(&[]), 0.0);
+/// ```
+pub fn sum<T>(data: &[T]) -> T
+where
+    T: Number,
+{
+    data.iter().fold(T::zero(), |acc, &x| acc + x)
+}
+
+/// Multiplies a sequence of numbers together
+///
+/// Returns one for an empty slice.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::product;
+/// assert_eq!(product(&[2, 3, 4]), 24);
+/// assert_eq!(product::<i32>(&[]), 1);
+/// ```
+pub fn product<T>(data: &[T]) -> T
+where
+    T: Number,
+{
+    data.iter().fold(T::one(), |acc, &x| acc * x)
+}
+
+/// Adds up a sequence of integers, failing instead of overflowing
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::checked_sum;
+/// assert_eq!(checked_sum(&[1u8, 2, 3]).unwrap(), 6);
+/// assert!(checked_sum(&[200u8, 100]).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::OutOfRange` if the sum overflows `T`
+pub fn checked_sum<T>(data: &[T]) -> MathResult<T>
+where
+    T: Number + CheckedAdd,
+{
+    data.iter().try_fold(T::zero(), |acc, x| {
+        acc.checked_add(x)
+            .ok_or_else(|| MathError::OutOfRange(format!("sum overflows at {:?}", x)))
+    })
+}
+
+/// Adds two integers, failing instead of overflowing
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::checked_add;
+/// assert_eq!(checked_add(250u8, 5).unwrap(), 255);
+/// assert!(checked_add(250u8, 6).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::OutOfRange` if the result does not fit in `T`
+pub fn checked_add<T>(a: T, b: T) -> MathResult<T>
+where
+    T: Number + CheckedAdd,
+{
+    a.checked_add(&b)
+        .ok_or_else(|| MathError::OutOfRange(format!("{:?} + {:?} overflows", a, b)))
+}
+
+/// Subtracts two integers, failing instead of overflowing
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::checked_sub;
+/// assert_eq!(checked_sub(5u8, 5).unwrap(), 0);
+/// assert!(checked_sub(5u8, 6).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::OutOfRange` if the result does not fit in `T`
+pub fn checked_sub<T>(a: T, b: T) -> MathResult<T>
+where
+    T: Number + CheckedSub,
+{
+    a.checked_sub(&b)
+        .ok_or_else(|| MathError::OutOfRange(format!("{:?} - {:?} overflows", a, b)))
+}
+
+/// Multiplies two integers, failing instead of overflowing
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::checked_mul;
+/// assert_eq!(checked_mul(51u8, 5).unwrap(), 255);
+/// assert!(checked_mul(16u8, 16).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::OutOfRange` if the result does not fit in `T`
+pub fn checked_mul<T>(a: T, b: T) -> MathResult<T>
+where
+    T: Number + CheckedMul,
+{
+    a.checked_mul(&b)
+        .ok_or_else(|| MathError::OutOfRange(format!("{:?} * {:?} overflows", a, b)))
+}
+
+/// Divides two integers, failing instead of dividing by zero or overflowing
+///
+/// The only overflowing integer division is the minimum of a signed type
+/// divided by `-1` (e.g. `i32::MIN / -1`), whose result does not fit. For
+/// floats, use [`safe_div_f64`].
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::safe_div;
+/// assert_eq!(safe_div(7, 2).unwrap(), 3);
+/// assert!(safe_div(7, 0).is_err());
+/// assert!(safe_div(i32::MIN, -1).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::DivisionByZero` if `b` is zero
+/// Returns `MathError::OutOfRange` if the quotient overflows `T`
+pub fn safe_div<T>(a: T, b: T) -> MathResult<T>
+where
+    T: Number + CheckedDiv,
+{
+    if b == T::zero() {
+        return Err(MathError::DivisionByZero);
+    }
+    
+    a.checked_div(&b)
+        .ok_or_else(|| MathError::OutOfRange(format!("{:?} / {:?} overflows", a, b)))
+}
+
+/// Divides two floats, failing instead of dividing by zero
+///
+/// Rejects the zero divisors (`0.0` and `-0.0`) that would otherwise give an
+/// infinite or NaN result.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::safe_div_f64;
+/// assert_eq!(safe_div_f64(1.0, 4.0).unwrap(), 0.25);
+/// assert!(safe_div_f64(1.0, 0.0).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::DivisionByZero` if `b` is zero
+pub fn safe_div_f64(a: f64, b: f64) -> MathResult<f64> {
+    if b == 0.0 {
+        return Err(MathError::DivisionByZero);
+    }
+    
+    Ok(a / b)
+}
+
 /// Checks if a number is prime
 ///
 /// # Examples
@@ -139,6 +346,464 @@ where
     Ok(result)
 }
 
+/// Calculates `n!` as a `u128`
+///
+/// Supports every `n` up to and including 34; `35!` exceeds `u128::MAX`.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::factorial_u128;
+/// assert_eq!(factorial_u128(25).unwrap(), 15_511_210_043_330_985_984_000_000);
+/// assert!(factorial_u128(35).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::OutOfRange` if the result would overflow
+pub fn factorial_u128(n: u64) -> MathResult<u128> {
+    (1..=n as u128).try_fold(1u128, |acc, i| {
+        acc.checked_mul(i)
+            .ok_or_else(|| MathError::OutOfRange("factorial overflow".to_string()))
+    })
+}
+
+/// Calculates the binomial coefficient C(n,r) as a `u128`
+///
+/// Each step divides out common factors before multiplying, so no
+/// intermediate value exceeds the final result. Every `n` up to and
+/// including 131 is supported for all `r`; larger `n` work whenever the
+/// result itself fits in a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::binomial_u128;
+/// assert_eq!(binomial_u128(100, 50).unwrap(), 100_891_344_545_564_193_334_812_497_256);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if `r` is greater than `n`
+/// Returns `MathError::OutOfRange` if the result would overflow
+pub fn binomial_u128(n: u64, r: u64) -> MathResult<u128> {
+    if r > n {
+        return Err(MathError::InvalidInput("r cannot be greater than n".to_string()));
+    }
+    
+    let (n, r) = (n as u128, r.min(n - r) as u128);
+    
+    let mut result: u128 = 1;
+    for i in 0..r {
+        let mut numerator = n - i;
+        let mut denominator = i + 1;
+        
+        let g = gcd(numerator, denominator);
+        numerator /= g;
+        denominator /= g;
+        
+        // `result * numerator` is divisible by `denominator`, and the
+        // numerator no longer shares factors with it
+        result /= denominator;
+        result = result
+            .checked_mul(numerator)
+            .ok_or_else(|| MathError::OutOfRange("binomial overflow".to_string()))?;
+    }
+    
+    Ok(result)
+}
+
+/// A closed numeric interval `[start, end]`
+///
+/// The bounds are private so every interval goes through [`Interval::new`]
+/// and is guaranteed to be ordered and free of NaN.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    start: f64,
+    end: f64,
+}
+
+impl Interval {
+    /// Creates a new interval
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::InvalidInput` if `start` is greater than `end` or
+    /// either bound is NaN
+    pub fn new(start: f64, end: f64) -> MathResult<Self> {
+        if start.is_nan() || end.is_nan() {
+            Err(MathError::InvalidInput("interval bounds must not be NaN".to_string()))
+        } else if start > end {
+            Err(MathError::InvalidInput("interval start must not exceed end".to_string()))
+        } else {
+            Ok(Interval { start, end })
+        }
+    }
+    
+    /// Returns the lower bound
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+    
+    /// Returns the upper bound
+    pub fn end(&self) -> f64 {
+        self.end
+    }
+    
+    /// Checks if the two intervals share at least one point
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+    
+    /// Returns the interval covered by both, if any
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        if self.overlaps(other) {
+            Some(Interval {
+                start: self.start.max(other.start),
+                end: self.end.min(other.end),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Sorts the intervals and coalesces any that overlap or touch
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::{merge_intervals, Interval};
+/// let merged = merge_intervals(vec![
+///     Interval::new(1.0, 3.0).unwrap(),
+///     Interval::new(2.0, 6.0).unwrap(),
+///     Interval::new(8.0, 10.0).unwrap(),
+/// ]);
+/// assert_eq!(merged, vec![Interval::new(1.0, 6.0).unwrap(), Interval::new(8.0, 10.0).unwrap()]);
+/// ```
+pub fn merge_intervals(mut intervals: Vec<Interval>) -> Vec<Interval> {
+    intervals.sort_by(|a, b| a.start.partial_cmp(&b.start).unwrap());
+    
+    let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+    for interval in intervals {
+        match merged.last_mut() {
+            Some(last) if interval.start <= last.end => {
+                last.end = last.end.max(interval.end);
+            }
+            _ => merged.push(interval),
+        }
+    }
+    
+    merged
+}
+
+/// Numerically integrates `f` over `[a, b]` using the composite Simpson's rule
+///
+/// `n` is the number of subintervals and must be even. If `a > b` the
+/// result is negated, matching the usual convention for reversed bounds.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::integrate;
+/// let area = integrate(|x| x * x, 0.0, 1.0, 10).unwrap();
+/// assert!((area - 1.0 / 3.0).abs() < 1e-12);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if `n` is zero or odd
+pub fn integrate<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, n: usize) -> MathResult<f64> {
+    if n == 0 || n % 2 == 1 {
+        return Err(MathError::InvalidInput("n must be even and positive".to_string()));
+    }
+    
+    if a > b {
+        return integrate(f, b, a, n).map(|area| -area);
+    }
+    
+    let h = (b - a) / n as f64;
+    let mut sum = f(a) + f(b);
+    for i in 1..n {
+        let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+        sum += weight * f(a + i as f64 * h);
+    }
+    
+    Ok(sum * h / 3.0)
+}
+
+/// Numerically integrates `f` over `[a, b]` using the composite trapezoidal rule
+///
+/// Less accurate than [`integrate`] for smooth functions, but works with any
+/// positive number of subintervals.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::integrate_trapezoidal;
+/// let area = integrate_trapezoidal(|x| 2.0 * x, 0.0, 1.0, 4).unwrap();
+/// assert!((area - 1.0).abs() < 1e-12);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if `n` is zero
+pub fn integrate_trapezoidal<F: Fn(f64) -> f64>(f: F, a: f64, b: f64, n: usize) -> MathResult<f64> {
+    if n == 0 {
+        return Err(MathError::InvalidInput("n must be positive".to_string()));
+    }
+    
+    if a > b {
+        return integrate_trapezoidal(f, b, a, n).map(|area| -area);
+    }
+    
+    let h = (b - a) / n as f64;
+    let mut sum = (f(a) + f(b)) / 2.0;
+    for i in 1..n {
+        sum += f(a + i as f64 * h);
+    }
+    
+    Ok(sum * h)
+}
+
+/// Approximates the derivative of `f` at `x` using the central difference
+/// `(f(x + h) - f(x - h)) / 2h`
+///
+/// The truncation error shrinks with `h²`, but very small steps lose
+/// precision to floating-point cancellation in the numerator, so `h` should
+/// be small relative to `x` without approaching machine epsilon.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::derivative;
+/// let slope = derivative(|x| x * x, 3.0, 1e-5).unwrap();
+/// assert!((slope - 6.0).abs() < 1e-6);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::DivisionByZero` if `h` is zero
+pub fn derivative<F: Fn(f64) -> f64>(f: F, x: f64, h: f64) -> MathResult<f64> {
+    safe_div_f64(f(x + h) - f(x - h), 2.0 * h)
+}
+
+/// Approximates the derivative of `f` at `x` with a step scaled to `x`
+///
+/// Uses `h = ∛ε · max(|x|, 1)`, which balances truncation and rounding error
+/// for the central difference formula.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::derivative_at;
+/// let slope = derivative_at(f64::sin, 0.0);
+/// assert!((slope - 1.0).abs() < 1e-8);
+/// ```
+pub fn derivative_at<F: Fn(f64) -> f64>(f: F, x: f64) -> f64 {
+    let h = f64::EPSILON.cbrt() * x.abs().max(1.0);
+    (f(x + h) - f(x - h)) / (2.0 * h)
+}
+
+/// Checks that `radix` is a supported base
+fn check_radix(radix: u32) -> MathResult<()> {
+    if (2..=36).contains(&radix) {
+        Ok(())
+    } else {
+        Err(MathError::InvalidInput(format!("radix {} must be within 2..=36", radix)))
+    }
+}
+
+/// Formats a number in the given base using the digits `0-9` and `a-z`
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::to_radix;
+/// assert_eq!(to_radix(255, 16).unwrap(), "ff");
+/// assert_eq!(to_radix(5, 2).unwrap(), "101");
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if `radix` is not within `2..=36`
+pub fn to_radix(mut n: u64, radix: u32) -> MathResult<String> {
+    check_radix(radix)?;
+    if n == 0 {
+        return Ok("0".to_string());
+    }
+    
+    let radix = radix as u64;
+    let mut digits = Vec::new();
+    while n > 0 {
+        digits.push(std::char::from_digit((n % radix) as u32, radix as u32).unwrap());
+        n /= radix;
+    }
+    Ok(digits.iter().rev().collect())
+}
+
+/// Parses a number written in the given base
+///
+/// Letters are accepted in either case.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::from_radix;
+/// assert_eq!(from_radix("ff", 16).unwrap(), 255);
+/// assert_eq!(from_radix("Zz", 36).unwrap(), 1295);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if `radix` is not within `2..=36`, the
+/// string is empty, or it contains a digit not valid in that base
+/// Returns `MathError::OutOfRange` if the value does not fit in a `u64`
+pub fn from_radix(s: &str, radix: u32) -> MathResult<u64> {
+    check_radix(radix)?;
+    if s.is_empty() {
+        return Err(MathError::InvalidInput("empty number".to_string()));
+    }
+    
+    s.chars().try_fold(0u64, |acc, c| {
+        let digit = c.to_digit(radix).ok_or_else(|| {
+            MathError::InvalidInput(format!("'{}' is not a valid base-{} digit", c, radix))
+        })?;
+        acc.checked_mul(radix as u64)
+            .and_then(|v| v.checked_add(digit as u64))
+            .ok_or_else(|| MathError::OutOfRange(format!("{} overflows u64", s)))
+    })
+}
+
+/// A fraction kept in lowest terms with a positive denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Fraction {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Fraction {
+    /// Creates a new fraction, reducing it to lowest terms
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_utils::arithmetic::Fraction;
+    /// let f = Fraction::new(6, -8).unwrap();
+    /// assert_eq!((f.numerator(), f.denominator()), (-3, 4));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::DivisionByZero` if `denominator` is zero
+    /// Returns `MathError::OutOfRange` if either part is `i64::MIN`
+    pub fn new(numerator: i64, denominator: i64) -> MathResult<Self> {
+        if denominator == 0 {
+            return Err(MathError::DivisionByZero);
+        }
+        if numerator == i64::MIN || denominator == i64::MIN {
+            return Err(MathError::OutOfRange("fraction part cannot be negated".to_string()));
+        }
+        
+        let divisor = gcd(numerator.abs(), denominator.abs());
+        let sign = denominator.signum();
+        Ok(Fraction {
+            numerator: sign * numerator / divisor,
+            denominator: sign * denominator / divisor,
+        })
+    }
+    
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+    
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+    
+    /// Converts the fraction to the nearest `f64`
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.numerator, self.denominator)
+    }
+}
+
+/// Finds the fraction closest to `x` whose denominator is at most `max_denominator`
+///
+/// Walks the continued-fraction expansion of `x`, stopping at the last
+/// convergent within the limit, then checks whether the best semiconvergent
+/// between that and the next convergent lands closer.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::arithmetic::{rationalize, Fraction};
+/// assert_eq!(rationalize(0.75, 10).unwrap(), Fraction::new(3, 4).unwrap());
+/// assert_eq!(rationalize(std::f64::consts::PI, 10).unwrap().to_string(), "22/7");
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if `x` is not finite or `max_denominator` is zero
+/// Returns `MathError::OutOfRange` if `x` does not fit in an `i64` numerator
+pub fn rationalize(x: f64, max_denominator: u64) -> MathResult<Fraction> {
+    if !x.is_finite() {
+        return Err(MathError::InvalidInput("value must be finite".to_string()));
+    }
+    if max_denominator == 0 {
+        return Err(MathError::InvalidInput("max_denominator must be positive".to_string()));
+    }
+    if x.abs() >= i64::MAX as f64 {
+        return Err(MathError::OutOfRange("value too large for a fraction".to_string()));
+    }
+    
+    let sign = if x < 0.0 { -1 } else { 1 };
+    let target = x.abs();
+    let max_denominator = max_denominator.min(i64::MAX as u64);
+    
+    // Convergents p/q, seeded with the conventional 0/1 and 1/0
+    let (mut p0, mut q0, mut p1, mut q1) = (0u64, 1u64, 1u64, 0u64);
+    let mut remainder = target;
+    let mut exact = false;
+    
+    loop {
+        let term = remainder.floor() as u64;
+        let q2 = q0.saturating_add(term.saturating_mul(q1));
+        if q2 > max_denominator {
+            break;
+        }
+        let p2 = p0.saturating_add(term.saturating_mul(p1));
+        (p0, q0, p1, q1) = (p1, q1, p2, q2);
+        
+        let fract = remainder - remainder.floor();
+        if fract < f64::EPSILON || (p1 as f64 / q1 as f64 - target).abs() < f64::EPSILON * target {
+            exact = true;
+            break;
+        }
+        remainder = 1.0 / fract;
+    }
+    
+    let (mut numerator, mut denominator) = (p1, q1);
+    if !exact {
+        // Largest semiconvergent that still respects the limit
+        let k = (max_denominator - q0) / q1;
+        let (semi_p, semi_q) = (p0 + k * p1, q0 + k * q1);
+        let semi_error = (semi_p as f64 / semi_q as f64 - target).abs();
+        let convergent_error = (p1 as f64 / q1 as f64 - target).abs();
+        if semi_error < convergent_error {
+            numerator = semi_p;
+            denominator = semi_q;
+        }
+    }
+    
+    Fraction::new(sign * numerator as i64, denominator as i64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,6 +822,17 @@ mod tests {
         assert_eq!(lcm(8, 12), 24);
     }
     
+    #[test]
+    fn test_checked_lcm() {
+        assert_eq!(checked_lcm(4, 6).unwrap(), 12);
+        assert_eq!(checked_lcm(0, 5).unwrap(), 0);
+        assert_eq!(checked_lcm(5, 0).unwrap(), 0);
+        assert!(matches!(
+            checked_lcm(u64::MAX, u64::MAX - 1),
+            Err(MathError::OutOfRange(_))
+        ));
+    }
+    
     #[test]
     fn test_is_prime() {
         assert!(is_prime(2));
@@ -181,4 +857,234 @@ mod tests {
         assert!(binomial(5, 6).is_err());
         assert!(binomial(-1, 2).is_err());
     }
+    
+    #[test]
+    fn test_integrate() {
+        let simpson = integrate(|x| x * x, 0.0, 1.0, 2).unwrap();
+        assert!((simpson - 1.0 / 3.0).abs() < 1e-12);
+        
+        let sine = integrate(f64::sin, 0.0, std::f64::consts::PI, 100).unwrap();
+        assert!((sine - 2.0).abs() < 1e-6);
+        
+        let reversed = integrate(|x| x * x, 1.0, 0.0, 10).unwrap();
+        assert!((reversed + 1.0 / 3.0).abs() < 1e-12);
+        
+        assert!(integrate(|x| x, 0.0, 1.0, 0).is_err());
+        assert!(integrate(|x| x, 0.0, 1.0, 3).is_err());
+    }
+    
+    #[test]
+    fn test_integrate_trapezoidal() {
+        let trapezoid = integrate_trapezoidal(|x| x * x, 0.0, 1.0, 100).unwrap();
+        let simpson = integrate(|x| x * x, 0.0, 1.0, 100).unwrap();
+        assert!((trapezoid - 1.0 / 3.0).abs() < 1e-4);
+        assert!((simpson - 1.0 / 3.0).abs() < (trapezoid - 1.0 / 3.0).abs());
+        
+        let reversed = integrate_trapezoidal(|x| x * x, 1.0, 0.0, 100).unwrap();
+        assert!((reversed + trapezoid).abs() < 1e-12);
+        
+        assert!(integrate_trapezoidal(|x| x, 0.0, 1.0, 0).is_err());
+    }
+    
+    #[test]
+    fn test_derivative() {
+        let slope = derivative(|x| x * x, 3.0, 1e-4).unwrap();
+        assert!((slope - 6.0).abs() < 1e-6);
+        
+        assert!(matches!(derivative(|x| x, 1.0, 0.0), Err(MathError::DivisionByZero)));
+    }
+    
+    #[test]
+    fn test_derivative_at() {
+        assert!((derivative_at(|x| x * x, 3.0) - 6.0).abs() < 1e-6);
+        assert!((derivative_at(f64::exp, 1e6_f64.ln()) - 1e6).abs() < 1e-2);
+        assert!((derivative_at(f64::cos, 0.0)).abs() < 1e-8);
+    }
+    
+    #[test]
+    fn test_factorial_u128() {
+        assert_eq!(factorial_u128(0).unwrap(), 1);
+        assert_eq!(factorial_u128(20).unwrap(), 2_432_902_008_176_640_000);
+        assert_eq!(
+            factorial_u128(34).unwrap(),
+            295_232_799_039_604_140_847_618_609_643_520_000_000
+        );
+        assert!(matches!(factorial_u128(35), Err(MathError::OutOfRange(_))));
+    }
+    
+    #[test]
+    fn test_binomial_u128() {
+        assert_eq!(binomial_u128(5, 2).unwrap(), 10);
+        assert_eq!(binomial_u128(10, 0).unwrap(), 1);
+        assert_eq!(binomial_u128(10, 10).unwrap(), 1);
+        assert_eq!(binomial_u128(67, 33).unwrap(), 14_226_520_737_620_288_370);
+        assert_eq!(
+            binomial_u128(131, 65).unwrap(),
+            188_694_833_082_770_476_622_296_176_145_946_360_850
+        );
+        assert!(matches!(binomial_u128(132, 66), Err(MathError::OutOfRange(_))));
+        assert!(matches!(binomial_u128(5, 6), Err(MathError::InvalidInput(_))));
+    }
+    
+    #[test]
+    fn test_interval() {
+        let a = Interval::new(1.0, 5.0).unwrap();
+        let b = Interval::new(4.0, 8.0).unwrap();
+        let c = Interval::new(5.0, 6.0).unwrap();
+        let d = Interval::new(9.0, 10.0).unwrap();
+        
+        assert!(a.overlaps(&b));
+        assert!(a.overlaps(&c));
+        assert!(!a.overlaps(&d));
+        
+        assert_eq!(a.intersection(&b), Some(Interval::new(4.0, 5.0).unwrap()));
+        assert_eq!(a.intersection(&c), Some(Interval::new(5.0, 5.0).unwrap()));
+        assert_eq!(a.intersection(&d), None);
+        
+        assert!(Interval::new(2.0, 1.0).is_err());
+        assert!(Interval::new(f64::NAN, 1.0).is_err());
+        assert_eq!((a.start(), a.end()), (1.0, 5.0));
+    }
+    
+    #[test]
+    fn test_merge_intervals() {
+        let intervals = vec![
+            Interval::new(8.0, 10.0).unwrap(),
+            Interval::new(1.0, 3.0).unwrap(),
+            Interval::new(2.0, 6.0).unwrap(),
+        ];
+        assert_eq!(
+            merge_intervals(intervals),
+            vec![Interval::new(1.0, 6.0).unwrap(), Interval::new(8.0, 10.0).unwrap()]
+        );
+        
+        // Touching endpoints merge
+        let touching = vec![Interval::new(1.0, 2.0).unwrap(), Interval::new(2.0, 3.0).unwrap()];
+        assert_eq!(merge_intervals(touching), vec![Interval::new(1.0, 3.0).unwrap()]);
+        
+        assert!(merge_intervals(Vec::new()).is_empty());
+    }
+    
+    #[test]
+    fn test_fraction_new() {
+        let f = Fraction::new(10, 4).unwrap();
+        assert_eq!((f.numerator(), f.denominator()), (5, 2));
+        assert_eq!(Fraction::new(3, -9).unwrap(), Fraction::new(-1, 3).unwrap());
+        assert_eq!(Fraction::new(0, -5).unwrap().to_string(), "0/1");
+        assert_eq!(Fraction::new(1, 4).unwrap().to_f64(), 0.25);
+        
+        assert!(matches!(Fraction::new(1, 0), Err(MathError::DivisionByZero)));
+    }
+    
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn test_rationalize() {
+        assert_eq!(rationalize(0.3333333, 10).unwrap(), Fraction::new(1, 3).unwrap());
+        assert_eq!(rationalize(3.14159, 100).unwrap(), Fraction::new(311, 99).unwrap());
+        assert_eq!(rationalize(3.14159, 10).unwrap(), Fraction::new(22, 7).unwrap());
+        assert_eq!(rationalize(0.1, 1000).unwrap(), Fraction::new(1, 10).unwrap());
+        
+        // A semiconvergent beats the last convergent here: 0.6 -> 1/2 not 1/1
+        assert_eq!(rationalize(0.6, 2).unwrap(), Fraction::new(1, 2).unwrap());
+    }
+    
+    #[test]
+    fn test_rationalize_signs_and_integers() {
+        assert_eq!(rationalize(-0.75, 10).unwrap(), Fraction::new(-3, 4).unwrap());
+        assert_eq!(rationalize(5.0, 10).unwrap(), Fraction::new(5, 1).unwrap());
+        assert_eq!(rationalize(-2.0, 1).unwrap(), Fraction::new(-2, 1).unwrap());
+        assert_eq!(rationalize(0.0, 7).unwrap(), Fraction::new(0, 1).unwrap());
+        assert_eq!(rationalize(0.4, 1).unwrap(), Fraction::new(0, 1).unwrap());
+        
+        assert!(matches!(rationalize(f64::NAN, 10), Err(MathError::InvalidInput(_))));
+        assert!(matches!(rationalize(0.5, 0), Err(MathError::InvalidInput(_))));
+        assert!(matches!(rationalize(1e20, 10), Err(MathError::OutOfRange(_))));
+    }
+    
+    #[test]
+    fn test_sum_and_product() {
+        assert_eq!(sum(&[1, 2, 3]), 6);
+        assert_eq!(sum(&[0.5, 0.25, 0.25]), 1.0);
+        assert_eq!(sum::<u64>(&[]), 0);
+        
+        assert_eq!(product(&[1, 2, 3, 4]), 24);
+        assert_eq!(product(&[0.5, 4.0]), 2.0);
+        assert_eq!(product::<f64>(&[]), 1.0);
+    }
+    
+    #[test]
+    fn test_checked_sum() {
+        assert_eq!(checked_sum(&[1i32, 2, 3]).unwrap(), 6);
+        assert_eq!(checked_sum::<i64>(&[]).unwrap(), 0);
+        assert_eq!(checked_sum(&[i32::MAX, -1, 1]).unwrap(), i32::MAX);
+        
+        assert!(matches!(checked_sum(&[i32::MAX, 1]), Err(MathError::OutOfRange(_))));
+        assert!(matches!(checked_sum(&[u8::MAX, 1]), Err(MathError::OutOfRange(_))));
+    }
+    
+    #[test]
+    fn test_checked_operations_near_u8_max() {
+        assert_eq!(checked_add(u8::MAX - 1, 1).unwrap(), u8::MAX);
+        assert!(matches!(checked_add(u8::MAX, 1), Err(MathError::OutOfRange(_))));
+        
+        assert_eq!(checked_sub(u8::MAX, u8::MAX).unwrap(), 0);
+        assert!(matches!(checked_sub(0u8, 1), Err(MathError::OutOfRange(_))));
+        
+        assert_eq!(checked_mul(u8::MAX, 1).unwrap(), u8::MAX);
+        assert!(matches!(checked_mul(u8::MAX, 2), Err(MathError::OutOfRange(_))));
+        
+        assert_eq!(checked_sub(i32::MIN + 1, 1).unwrap(), i32::MIN);
+        assert!(checked_mul(i64::MIN, -1).is_err());
+    }
+    
+    #[test]
+    fn test_safe_div() {
+        assert_eq!(safe_div(10, 3).unwrap(), 3);
+        assert_eq!(safe_div(-9i64, 3).unwrap(), -3);
+        assert_eq!(safe_div(0u8, 5).unwrap(), 0);
+        assert!(matches!(safe_div(10, 0), Err(MathError::DivisionByZero)));
+        assert!(matches!(safe_div(0u32, 0), Err(MathError::DivisionByZero)));
+        assert!(matches!(safe_div(i32::MIN, -1), Err(MathError::OutOfRange(_))));
+        assert!(matches!(safe_div(i8::MIN, -1), Err(MathError::OutOfRange(_))));
+        assert_eq!(safe_div(i32::MIN, 1).unwrap(), i32::MIN);
+        
+        assert_eq!(safe_div_f64(1.0, 8.0).unwrap(), 0.125);
+        assert_eq!(safe_div_f64(-3.0, 2.0).unwrap(), -1.5);
+        assert!(matches!(safe_div_f64(1.0, 0.0), Err(MathError::DivisionByZero)));
+        assert!(matches!(safe_div_f64(1.0, -0.0), Err(MathError::DivisionByZero)));
+        assert!(matches!(safe_div_f64(0.0, 0.0), Err(MathError::DivisionByZero)));
+    }
+    
+    #[test]
+    fn test_to_radix() {
+        assert_eq!(to_radix(255, 16).unwrap(), "ff");
+        assert_eq!(to_radix(0, 2).unwrap(), "0");
+        assert_eq!(to_radix(35, 36).unwrap(), "z");
+        assert_eq!(to_radix(u64::MAX, 2).unwrap(), "1".repeat(64));
+        
+        assert!(matches!(to_radix(10, 1), Err(MathError::InvalidInput(_))));
+        assert!(matches!(to_radix(10, 37), Err(MathError::InvalidInput(_))));
+    }
+    
+    #[test]
+    fn test_from_radix() {
+        assert_eq!(from_radix("ff", 16).unwrap(), 255);
+        assert_eq!(from_radix("FF", 16).unwrap(), 255);
+        assert_eq!(from_radix("0", 7).unwrap(), 0);
+        
+        assert!(matches!(from_radix("12", 2), Err(MathError::InvalidInput(_))));
+        assert!(matches!(from_radix("", 10), Err(MathError::InvalidInput(_))));
+        assert!(matches!(from_radix("-1", 10), Err(MathError::InvalidInput(_))));
+        assert!(matches!(from_radix("1", 0), Err(MathError::InvalidInput(_))));
+        assert!(matches!(from_radix("18446744073709551616", 10), Err(MathError::OutOfRange(_))));
+    }
+    
+    #[test]
+    fn test_radix_round_trip() {
+        for &n in &[0, 1, 42, 1_000_000, u64::MAX] {
+            for &radix in &[2, 36] {
+                assert_eq!(from_radix(&to_radix(n, radix).unwrap(), radix).unwrap(), n);
+            }
+        }
+    }
 } 
\ No newline at end of file
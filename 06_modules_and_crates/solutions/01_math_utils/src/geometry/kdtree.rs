@@ -0,0 +1,211 @@
+//! Kd-tree module
+//!
+//! This module provides a 2D kd-tree for fast nearest-neighbor and
+//! radius queries over a fixed set of points.
+
+/// A node splitting the plane on `x` (axis 0) or `y` (axis 1)
+#[derive(Debug)]
+struct Node {
+    point: (f64, f64),
+    axis: usize,
+    left: Option<Box<Node>>,
+    right: Option<Box<Node>>,
+}
+
+/// A static kd-tree over 2D points
+#[derive(Debug)]
+pub struct KdTree {
+    root: Option<Box<Node>>,
+    len: usize,
+}
+
+/// Returns the coordinate of `point` along `axis`
+fn coord(point: (f64, f64), axis: usize) -> f64 {
+    if axis == 0 { point.0 } else { point.1 }
+}
+
+/// Calculates the squared distance between two points
+fn distance_squared(a: (f64, f64), b: (f64, f64)) -> f64 {
+    let dx = a.0 - b.0;
+    let dy = a.1 - b.1;
+    dx * dx + dy * dy
+}
+
+impl KdTree {
+    /// Builds a balanced tree, alternating the split axis and splitting at the median
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use math_utils::geometry::kdtree::KdTree;
+    ///
+    /// let tree = KdTree::new(vec![(0.0, 0.0), (5.0, 5.0), (9.0, 1.0)]);
+    /// assert_eq!(tree.nearest((6.0, 4.0)), Some((5.0, 5.0)));
+    /// ```
+    pub fn new(mut points: Vec<(f64, f64)>) -> Self {
+        let len = points.len();
+        KdTree {
+            root: Self::build(&mut points, 0),
+            len,
+        }
+    }
+    
+    fn build(points: &mut [(f64, f64)], depth: usize) -> Option<Box<Node>> {
+        if points.is_empty() {
+            return None;
+        }
+        
+        let axis = depth % 2;
+        points.sort_by(|a, b| coord(*a, axis).total_cmp(&coord(*b, axis)));
+        let median = points.len() / 2;
+        let (left, rest) = points.split_at_mut(median);
+        let (point, right) = rest.split_first_mut()?;
+        
+        Some(Box::new(Node {
+            point: *point,
+            axis,
+            left: Self::build(left, depth + 1),
+            right: Self::build(right, depth + 1),
+        }))
+    }
+    
+    /// Returns the number of points in the tree
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    
+    /// Returns true if the tree holds no points
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    
+    /// Finds the point closest to `query`, or `None` for an empty tree
+    pub fn nearest(&self, query: (f64, f64)) -> Option<(f64, f64)> {
+        let mut best = None;
+        if let Some(root) = &self.root {
+            Self::nearest_in(root, query, &mut best);
+        }
+        best.map(|(point, _)| point)
+    }
+    
+    fn nearest_in(node: &Node, query: (f64, f64), best: &mut Option<((f64, f64), f64)>) {
+        let dist = distance_squared(node.point, query);
+        if best.is_none_or(|(_, best_dist)| dist < best_dist) {
+            *best = Some((node.point, dist));
+        }
+        
+        // Search the side containing the query first; the other side can only
+        // hold a closer point if the splitting line is nearer than the best so far
+        let diff = coord(query, node.axis) - coord(node.point, node.axis);
+        let (near, far) = if diff < 0.0 {
+            (&node.left, &node.right)
+        } else {
+            (&node.right, &node.left)
+        };
+        
+        if let Some(near) = near {
+            Self::nearest_in(near, query, best);
+        }
+        if let Some(far) = far {
+            if best.is_none_or(|(_, best_dist)| diff * diff < best_dist) {
+                Self::nearest_in(far, query, best);
+            }
+        }
+    }
+    
+    /// Returns every point whose distance to `query` is at most `radius`
+    pub fn within_radius(&self, query: (f64, f64), radius: f64) -> Vec<(f64, f64)> {
+        let mut found = Vec::new();
+        if let Some(root) = &self.root {
+            Self::within_radius_in(root, query, radius, &mut found);
+        }
+        found
+    }
+    
+    fn within_radius_in(node: &Node, query: (f64, f64), radius: f64, found: &mut Vec<(f64, f64)>) {
+        if distance_squared(node.point, query) <= radius * radius {
+            found.push(node.point);
+        }
+        
+        let diff = coord(query, node.axis) - coord(node.point, node.axis);
+        if let Some(left) = &node.left {
+            if diff <= radius {
+                Self::within_radius_in(left, query, radius, found);
+            }
+        }
+        if let Some(right) = &node.right {
+            if diff >= -radius {
+                Self::within_radius_in(right, query, radius, found);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn sample_points() -> Vec<(f64, f64)> {
+        vec![
+            (2.0, 3.0),
+            (5.0, 4.0),
+            (9.0, 6.0),
+            (4.0, 7.0),
+            (8.0, 1.0),
+            (7.0, 2.0),
+            (1.0, 1.0),
+            (6.0, 8.0),
+        ]
+    }
+    
+    fn brute_force_nearest(points: &[(f64, f64)], query: (f64, f64)) -> (f64, f64) {
+        *points
+            .iter()
+            .min_by(|a, b| distance_squared(**a, query).total_cmp(&distance_squared(**b, query)))
+            .unwrap()
+    }
+    
+    #[test]
+    fn test_empty_tree() {
+        let tree = KdTree::new(Vec::new());
+        assert!(tree.is_empty());
+        assert_eq!(tree.nearest((0.0, 0.0)), None);
+        assert!(tree.within_radius((0.0, 0.0), 10.0).is_empty());
+    }
+    
+    #[test]
+    fn test_nearest_matches_brute_force() {
+        let points = sample_points();
+        let tree = KdTree::new(points.clone());
+        assert_eq!(tree.len(), points.len());
+        
+        assert_eq!(tree.nearest((9.0, 2.0)), Some((8.0, 1.0)));
+        assert_eq!(tree.nearest((4.0, 7.0)), Some((4.0, 7.0)));
+        
+        for x in -2..12 {
+            for y in -2..12 {
+                let query = (x as f64 + 0.3, y as f64 - 0.2);
+                let expected = brute_force_nearest(&points, query);
+                let found = tree.nearest(query).unwrap();
+                assert_eq!(distance_squared(found, query), distance_squared(expected, query));
+            }
+        }
+    }
+    
+    #[test]
+    fn test_within_radius() {
+        let tree = KdTree::new(sample_points());
+        
+        let mut found = tree.within_radius((6.0, 3.0), 2.0);
+        found.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(found, vec![(5.0, 4.0), (7.0, 2.0)]);
+        
+        // Points exactly on the circle are included
+        let mut found = tree.within_radius((1.0, 3.0), 2.0);
+        found.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(found, vec![(1.0, 1.0), (2.0, 3.0)]);
+        
+        assert!(tree.within_radius((20.0, 20.0), 1.0).is_empty());
+        assert_eq!(tree.within_radius((5.0, 5.0), 100.0).len(), 8);
+    }
+} 
\ No newline at end of file
@@ -2,10 +2,13 @@
 //!
 //! This module provides geometric calculations and transformations.
 
+pub mod kdtree;
 pub mod shapes;
 pub mod transformations;
+pub mod vecn;
 
 use std::f64::consts::PI;
+use crate::{MathError, MathResult};
 
 /// Common geometric constants
 pub const TAU: f64 = 2.0 * PI;
@@ -74,6 +77,66 @@ impl Vector {
     }
 }
 
+/// Calculates the convex hull of a set of points with Andrew's monotone chain
+/// algorithm
+///
+/// The hull vertices are returned in counter-clockwise order, starting from
+/// the point with the smallest `x` (then smallest `y`). Duplicate points and
+/// points lying on a hull edge are left out, so collinear input collapses to
+/// its two end points. If fewer than three distinct points remain, they are
+/// returned sorted by `x`, then `y`.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::geometry::convex_hull;
+///
+/// let points = [(0.0, 0.0), (2.0, 0.0), (1.0, 1.0), (2.0, 2.0), (0.0, 2.0)];
+/// let hull = convex_hull(&points).unwrap();
+/// assert_eq!(hull, vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if any coordinate is NaN or infinite
+pub fn convex_hull(points: &[(f64, f64)]) -> MathResult<Vec<(f64, f64)>> {
+    if points.iter().any(|(x, y)| !x.is_finite() || !y.is_finite()) {
+        return Err(MathError::InvalidInput("points must have finite coordinates".to_string()));
+    }
+    
+    // Adding 0.0 turns -0.0 into 0.0, so total_cmp orders the points
+    // lexicographically and dedup merges signed-zero duplicates
+    let mut sorted: Vec<(f64, f64)> = points.iter().map(|&(x, y)| (x + 0.0, y + 0.0)).collect();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    sorted.dedup();
+    if sorted.len() < 3 {
+        return Ok(sorted);
+    }
+    
+    // Positive when o -> a -> b turns counter-clockwise
+    let turn = |o: (f64, f64), a: (f64, f64), b: (f64, f64)| {
+        Vector::new(a.0 - o.0, a.1 - o.1).cross(&Vector::new(b.0 - o.0, b.1 - o.1))
+    };
+    
+    // Builds one half of the hull, dropping points that don't turn left
+    let half_hull = |points: &mut dyn Iterator<Item = &(f64, f64)>| {
+        let mut chain: Vec<(f64, f64)> = Vec::new();
+        for &p in points {
+            while chain.len() >= 2 && turn(chain[chain.len() - 2], chain[chain.len() - 1], p) <= 0.0 {
+                chain.pop();
+            }
+            chain.push(p);
+        }
+        // The last point starts the other half
+        chain.pop();
+        chain
+    };
+    
+    let mut hull = half_hull(&mut sorted.iter());
+    hull.extend(half_hull(&mut sorted.iter().rev()));
+    Ok(hull)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -98,4 +161,53 @@ mod tests {
         assert_relative_eq!(v1.dot(&v2), 11.0);
         assert_relative_eq!(v1.cross(&v2), 2.0);
     }
+    
+    #[test]
+    fn test_convex_hull_excludes_interior_points() {
+        let points = [
+            (1.0, 1.0),
+            (0.0, 0.0),
+            (0.0, 4.0),
+            (2.0, 3.0),
+            (4.0, 0.0),
+            (4.0, 4.0),
+            (3.0, 1.0),
+        ];
+        let hull = convex_hull(&points).unwrap();
+        assert_eq!(hull, vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)]);
+        
+        let vertices = hull.iter().map(|&(x, y)| Point::new(x, y)).collect();
+        let polygon = shapes::Polygon::new(vertices).unwrap();
+        assert_relative_eq!(shapes::Shape::area(&polygon), 16.0);
+    }
+    
+    #[test]
+    fn test_convex_hull_degenerate_input() {
+        assert_eq!(convex_hull(&[]).unwrap(), vec![]);
+        
+        // Two identical points collapse to one
+        assert_eq!(convex_hull(&[(1.0, 2.0), (1.0, 2.0)]).unwrap(), vec![(1.0, 2.0)]);
+        assert_eq!(convex_hull(&[(3.0, 1.0), (0.0, 0.0)]).unwrap(), vec![(0.0, 0.0), (3.0, 1.0)]);
+        
+        // Duplicates and points on edges are dropped
+        let with_duplicates = [(0.0, 0.0), (2.0, 0.0), (1.0, 0.0), (0.0, 0.0), (1.0, 2.0), (2.0, 0.0)];
+        assert_eq!(convex_hull(&with_duplicates).unwrap(), vec![(0.0, 0.0), (2.0, 0.0), (1.0, 2.0)]);
+        
+        let collinear = [(3.0, 3.0), (1.0, 1.0), (2.0, 2.0), (0.0, 0.0)];
+        assert_eq!(convex_hull(&collinear).unwrap(), vec![(0.0, 0.0), (3.0, 3.0)]);
+        
+        let same_point = [(5.0, 5.0); 4];
+        assert_eq!(convex_hull(&same_point).unwrap(), vec![(5.0, 5.0)]);
+        
+        assert!(matches!(convex_hull(&[(0.0, f64::NAN)]), Err(MathError::InvalidInput(_))));
+    }
+    
+    #[test]
+    fn test_convex_hull_signed_zeros() {
+        let points = [(-0.0, 5.0), (0.0, 0.0), (0.0, 10.0), (1.0, 5.0)];
+        assert_eq!(convex_hull(&points).unwrap(), vec![(0.0, 0.0), (1.0, 5.0), (0.0, 10.0)]);
+        
+        let duplicates = [(-0.0, 1.0), (0.0, 0.0), (0.0, 1.0)];
+        assert_eq!(convex_hull(&duplicates).unwrap(), vec![(0.0, 0.0), (0.0, 1.0)]);
+    }
 } 
\ No newline at end of file
@@ -16,6 +16,13 @@ pub trait Shape {
     
     /// Checks if a point is inside the shape
     fn contains(&self, point: Point) -> bool;
+    
+    /// Checks if a point given as `(x, y)` coordinates is inside the shape
+    ///
+    /// Points lying exactly on the boundary count as inside.
+    fn contains_point(&self, p: (f64, f64)) -> bool {
+        self.contains(Point::new(p.0, p.1))
+    }
 }
 
 /// A circle defined by its center and radius
@@ -114,6 +121,82 @@ impl Triangle {
         let v2 = Vector::new(self.c.x - self.a.x, self.c.y - self.a.y);
         v1.cross(&v2) / 2.0
     }
+    
+    /// Returns the lengths of the three sides, shortest first
+    pub fn sorted_sides(&self) -> [f64; 3] {
+        let mut sides = [
+            self.a.distance_to(&self.b),
+            self.b.distance_to(&self.c),
+            self.c.distance_to(&self.a),
+        ];
+        sides.sort_by(|x, y| x.total_cmp(y));
+        sides
+    }
+    
+    /// Checks whether both triangles have the same side lengths, within `epsilon`
+    ///
+    /// Position, rotation and reflection are ignored (SSS congruence).
+    pub fn is_congruent_to(&self, other: &Triangle, epsilon: f64) -> bool {
+        self.sorted_sides()
+            .iter()
+            .zip(other.sorted_sides().iter())
+            .all(|(x, y)| (x - y).abs() <= epsilon)
+    }
+    
+    /// Checks whether the side lengths of both triangles are proportional,
+    /// comparing the ratios of matching sides within `epsilon`
+    ///
+    /// A degenerate `other` with a zero-length side is never similar.
+    pub fn is_similar_to(&self, other: &Triangle, epsilon: f64) -> bool {
+        let ours = self.sorted_sides();
+        let theirs = other.sorted_sides();
+        if theirs[0] == 0.0 {
+            return false;
+        }
+        
+        let scale = ours[0] / theirs[0];
+        ours.iter()
+            .zip(theirs.iter())
+            .all(|(x, y)| (x / y - scale).abs() <= epsilon)
+    }
+    
+    /// Calculates the interior angles at `a`, `b` and `c`, in radians
+    ///
+    /// Uses the law of cosines on the side lengths.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::InvalidInput` if the sides violate the strict triangle
+    /// inequality (within `1e-10`), i.e. the vertices are collinear or coincide
+    pub fn angles(&self) -> Result<(f64, f64, f64), MathError> {
+        // Each side is named after the vertex it faces
+        let side_a = self.b.distance_to(&self.c);
+        let side_b = self.c.distance_to(&self.a);
+        let side_c = self.a.distance_to(&self.b);
+        
+        let longest = side_a.max(side_b).max(side_c);
+        if side_a + side_b + side_c - longest <= longest + 1e-10 {
+            return Err(MathError::InvalidInput("sides do not form a valid triangle".to_string()));
+        }
+        
+        let angle = |opposite: f64, x: f64, y: f64| {
+            ((x * x + y * y - opposite * opposite) / (2.0 * x * y)).clamp(-1.0, 1.0).acos()
+        };
+        Ok((
+            angle(side_a, side_b, side_c),
+            angle(side_b, side_c, side_a),
+            angle(side_c, side_a, side_b),
+        ))
+    }
+    
+    /// Checks whether any interior angle is within `epsilon` radians of a right angle
+    ///
+    /// Degenerate triangles are never right-angled.
+    pub fn is_right_angled(&self, epsilon: f64) -> bool {
+        self.angles().is_ok_and(|(a, b, c)| {
+            [a, b, c].iter().any(|angle| (angle - PI / 2.0).abs() <= epsilon)
+        })
+    }
 }
 
 impl Shape for Triangle {
@@ -138,6 +221,186 @@ impl Shape for Triangle {
     }
 }
 
+/// A simple polygon defined by its vertices in order
+#[derive(Debug, Clone)]
+pub struct Polygon {
+    pub vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// Creates a new polygon
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::InvalidInput` if fewer than three vertices are given
+    pub fn new(vertices: Vec<Point>) -> Result<Self, MathError> {
+        if vertices.len() < 3 {
+            Err(MathError::InvalidInput("polygon needs at least three vertices".to_string()))
+        } else {
+            Ok(Polygon { vertices })
+        }
+    }
+    
+    /// Iterates over the edges as pairs of consecutive vertices
+    fn edges(&self) -> impl Iterator<Item = (&Point, &Point)> {
+        self.vertices.iter().zip(self.vertices.iter().cycle().skip(1))
+    }
+}
+
+/// Checks whether `point` lies on the segment from `a` to `b`
+fn on_segment(point: &Point, a: &Point, b: &Point) -> bool {
+    let edge = Vector::new(b.x - a.x, b.y - a.y);
+    let to_point = Vector::new(point.x - a.x, point.y - a.y);
+    
+    edge.cross(&to_point).abs() < 1e-10
+        && point.x >= a.x.min(b.x) - 1e-10
+        && point.x <= a.x.max(b.x) + 1e-10
+        && point.y >= a.y.min(b.y) - 1e-10
+        && point.y <= a.y.max(b.y) + 1e-10
+}
+
+impl Shape for Polygon {
+    fn area(&self) -> f64 {
+        // Shoelace formula
+        let twice_area: f64 = self.edges()
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum();
+        twice_area.abs() / 2.0
+    }
+    
+    fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| a.distance_to(b)).sum()
+    }
+    
+    fn contains(&self, point: Point) -> bool {
+        if self.edges().any(|(a, b)| on_segment(&point, a, b)) {
+            return true;
+        }
+        
+        // Even-odd rule: count edges crossed by a ray cast to the right
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            if (a.y > point.y) != (b.y > point.y) {
+                let crossing_x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+                if point.x < crossing_x {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
+
+/// An axis-aligned ellipse defined by its center and semi-axes
+#[derive(Debug, Clone, Copy)]
+pub struct Ellipse {
+    pub center: Point,
+    /// Semi-axis along x
+    pub a: f64,
+    /// Semi-axis along y
+    pub b: f64,
+}
+
+impl Ellipse {
+    /// Creates a new ellipse
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::InvalidInput` if either semi-axis is negative
+    pub fn new(center: Point, a: f64, b: f64) -> Result<Self, MathError> {
+        if a < 0.0 || b < 0.0 {
+            Err(MathError::InvalidInput("semi-axes must be non-negative".to_string()))
+        } else {
+            Ok(Ellipse { center, a, b })
+        }
+    }
+}
+
+impl Shape for Ellipse {
+    fn area(&self) -> f64 {
+        PI * self.a * self.b
+    }
+    
+    /// Approximates the perimeter using Ramanujan's first formula, which is
+    /// exact for circles and within a fraction of a percent otherwise
+    fn perimeter(&self) -> f64 {
+        let (a, b) = (self.a, self.b);
+        PI * (3.0 * (a + b) - ((3.0 * a + b) * (a + 3.0 * b)).sqrt())
+    }
+    
+    fn contains(&self, point: Point) -> bool {
+        if self.a == 0.0 || self.b == 0.0 {
+            return self.center.distance_to(&point) == 0.0;
+        }
+        let dx = (point.x - self.center.x) / self.a;
+        let dy = (point.y - self.center.y) / self.b;
+        dx * dx + dy * dy <= 1.0
+    }
+}
+
+/// A regular polygon defined by its center, number of sides and side length
+///
+/// The first vertex points straight up from the center.
+#[derive(Debug, Clone, Copy)]
+pub struct RegularPolygon {
+    pub center: Point,
+    pub sides: u32,
+    pub side_length: f64,
+}
+
+impl RegularPolygon {
+    /// Creates a new regular polygon
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::InvalidInput` if there are fewer than three sides
+    /// or the side length is negative
+    pub fn new(center: Point, sides: u32, side_length: f64) -> Result<Self, MathError> {
+        if sides < 3 {
+            Err(MathError::InvalidInput("regular polygon needs at least three sides".to_string()))
+        } else if side_length < 0.0 {
+            Err(MathError::InvalidInput("side length must be non-negative".to_string()))
+        } else {
+            Ok(RegularPolygon { center, sides, side_length })
+        }
+    }
+    
+    /// Calculates the distance from the center to each vertex
+    pub fn circumradius(&self) -> f64 {
+        self.side_length / (2.0 * (PI / self.sides as f64).sin())
+    }
+    
+    /// Calculates the vertex positions in counter-clockwise order
+    pub fn vertices(&self) -> Vec<Point> {
+        let radius = self.circumradius();
+        let step = 2.0 * PI / self.sides as f64;
+        (0..self.sides)
+            .map(|i| {
+                let angle = PI / 2.0 + step * i as f64;
+                Point::new(
+                    self.center.x + radius * angle.cos(),
+                    self.center.y + radius * angle.sin(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl Shape for RegularPolygon {
+    fn area(&self) -> f64 {
+        let n = self.sides as f64;
+        n * self.side_length * self.side_length / (4.0 * (PI / n).tan())
+    }
+    
+    fn perimeter(&self) -> f64 {
+        self.sides as f64 * self.side_length
+    }
+    
+    fn contains(&self, point: Point) -> bool {
+        Polygon { vertices: self.vertices() }.contains(point)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -181,4 +444,178 @@ mod tests {
         assert!(triangle.contains(Point::new(1.0, 1.0)));
         assert!(!triangle.contains(Point::new(2.0, 3.0)));
     }
+    
+    #[test]
+    fn test_triangle_similarity_and_congruence() {
+        let original = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(0.0, 4.0),
+        );
+        // Same shape moved and mirrored
+        let moved = Triangle::new(
+            Point::new(10.0, 10.0),
+            Point::new(10.0, 13.0),
+            Point::new(6.0, 10.0),
+        );
+        let scaled = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(6.0, 0.0),
+            Point::new(0.0, 8.0),
+        );
+        let other = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 0.0),
+            Point::new(0.0, 1.0),
+        );
+        
+        assert_eq!(original.sorted_sides(), [3.0, 4.0, 5.0]);
+        assert!(original.is_congruent_to(&moved, 1e-10));
+        assert!(original.is_similar_to(&moved, 1e-10));
+        
+        assert!(original.is_similar_to(&scaled, 1e-10));
+        assert!(scaled.is_similar_to(&original, 1e-10));
+        assert!(!original.is_congruent_to(&scaled, 1e-10));
+        
+        assert!(!original.is_similar_to(&other, 1e-10));
+        assert!(!original.is_congruent_to(&other, 1e-10));
+    }
+    
+    #[test]
+    fn test_triangle_angles() {
+        let triangle = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(3.0, 0.0),
+            Point::new(0.0, 4.0),
+        );
+        let (a, b, c) = triangle.angles().unwrap();
+        assert_relative_eq!(a, PI / 2.0, epsilon = 1e-10);
+        assert_relative_eq!(b, (3.0f64 / 5.0).acos(), epsilon = 1e-10);
+        assert_relative_eq!(a + b + c, PI, epsilon = 1e-10);
+        assert!(triangle.is_right_angled(1e-9));
+        
+        let equilateral = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(1.0, 3.0f64.sqrt()),
+        );
+        let (a, b, c) = equilateral.angles().unwrap();
+        for angle in [a, b, c] {
+            assert_relative_eq!(angle, PI / 3.0, epsilon = 1e-10);
+        }
+        assert!(!equilateral.is_right_angled(1e-9));
+        
+        let collinear = Triangle::new(
+            Point::new(0.0, 0.0),
+            Point::new(1.0, 1.0),
+            Point::new(2.0, 2.0),
+        );
+        assert!(matches!(collinear.angles(), Err(MathError::InvalidInput(_))));
+        assert!(!collinear.is_right_angled(1e-9));
+    }
+    
+    #[test]
+    fn test_polygon() {
+        // An L-shaped (concave) polygon
+        let polygon = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 2.0),
+            Point::new(2.0, 2.0),
+            Point::new(2.0, 4.0),
+            Point::new(0.0, 4.0),
+        ]).unwrap();
+        
+        assert_relative_eq!(polygon.area(), 12.0);
+        assert_relative_eq!(polygon.perimeter(), 16.0);
+        
+        assert!(polygon.contains_point((1.0, 1.0)));
+        assert!(polygon.contains_point((1.0, 3.0)));
+        assert!(!polygon.contains_point((3.0, 3.0)));
+        assert!(!polygon.contains_point((-1.0, 1.0)));
+        
+        assert!(Polygon::new(vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0)]).is_err());
+    }
+    
+    #[test]
+    fn test_contains_point_on_boundary() {
+        let circle = Circle::new(Point::new(0.0, 0.0), 2.0).unwrap();
+        assert!(circle.contains_point((0.0, 1.0)));
+        assert!(circle.contains_point((2.0, 0.0)));
+        assert!(!circle.contains_point((2.0, 0.1)));
+        
+        let rect = Rectangle::new(Point::new(1.0, 1.0), 2.0, 3.0).unwrap();
+        assert!(rect.contains_point((2.0, 2.0)));
+        assert!(rect.contains_point((1.0, 4.0)));
+        assert!(rect.contains_point((3.0, 2.5)));
+        assert!(!rect.contains_point((0.5, 2.0)));
+        
+        let square = Polygon::new(vec![
+            Point::new(0.0, 0.0),
+            Point::new(2.0, 0.0),
+            Point::new(2.0, 2.0),
+            Point::new(0.0, 2.0),
+        ]).unwrap();
+        assert!(square.contains_point((2.0, 1.0)));
+        assert!(square.contains_point((0.0, 0.0)));
+        assert!(square.contains_point((1.0, 2.0)));
+        assert!(!square.contains_point((2.5, 1.0)));
+    }
+    
+    #[test]
+    fn test_ellipse() {
+        let ellipse = Ellipse::new(Point::new(1.0, 1.0), 3.0, 2.0).unwrap();
+        assert_relative_eq!(ellipse.area(), 6.0 * PI);
+        // Reference value from the complete elliptic integral of the second kind
+        assert_relative_eq!(ellipse.perimeter(), 15.865_439_9, max_relative = 1e-6);
+        
+        assert!(ellipse.contains_point((3.9, 1.0)));
+        assert!(ellipse.contains_point((1.0, 3.0)));
+        assert!(!ellipse.contains_point((3.0, 2.5)));
+        
+        // A circle is the special case a == b
+        let circle = Ellipse::new(Point::new(0.0, 0.0), 2.0, 2.0).unwrap();
+        assert_relative_eq!(circle.perimeter(), 4.0 * PI);
+        
+        assert!(Ellipse::new(Point::new(0.0, 0.0), -1.0, 1.0).is_err());
+    }
+    
+    #[test]
+    fn test_regular_polygon() {
+        let square = RegularPolygon::new(Point::new(0.0, 0.0), 4, 2.0).unwrap();
+        assert_relative_eq!(square.area(), 4.0, epsilon = 1e-10);
+        assert_relative_eq!(square.perimeter(), 8.0);
+        assert_relative_eq!(square.circumradius(), 2.0_f64.sqrt());
+        
+        let hexagon = RegularPolygon::new(Point::new(0.0, 0.0), 6, 1.0).unwrap();
+        assert_relative_eq!(hexagon.area(), 3.0 * 3.0_f64.sqrt() / 2.0);
+        assert!(hexagon.contains_point((0.0, 0.99)));
+        assert!(!hexagon.contains_point((0.0, 1.01)));
+        
+        assert!(matches!(
+            RegularPolygon::new(Point::new(0.0, 0.0), 2, 1.0),
+            Err(MathError::InvalidInput(_))
+        ));
+        assert!(RegularPolygon::new(Point::new(0.0, 0.0), 3, -1.0).is_err());
+    }
+    
+    #[test]
+    fn test_regular_polygon_converges_to_unit_circle() {
+        // Inscribed in the unit circle, so the circumradius is 1
+        let inscribed = |sides: u32| {
+            let side_length = 2.0 * (PI / sides as f64).sin();
+            RegularPolygon::new(Point::new(0.0, 0.0), sides, side_length).unwrap()
+        };
+        
+        let mut previous_error = f64::INFINITY;
+        for sides in [6, 60, 600, 6000] {
+            let polygon = inscribed(sides);
+            let error = PI - polygon.area();
+            assert!(error > 0.0 && error < previous_error);
+            previous_error = error;
+        }
+        
+        assert_relative_eq!(inscribed(6000).area(), PI, max_relative = 1e-6);
+        assert_relative_eq!(inscribed(6000).perimeter(), 2.0 * PI, max_relative = 1e-6);
+    }
 } 
\ No newline at end of file
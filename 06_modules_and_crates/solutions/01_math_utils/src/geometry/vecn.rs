@@ -0,0 +1,169 @@
+//! N-dimensional vectors module
+//!
+//! This module provides a vector type with an arbitrary number of
+//! dimensions, complementing the fixed 2D `Point` and `Vector`.
+
+use crate::{MathError, MathResult, Number};
+
+/// A point or vector in N-dimensional space
+#[derive(Debug, Clone, PartialEq)]
+pub struct VecN<T> {
+    pub components: Vec<T>,
+}
+
+impl<T: Number> VecN<T> {
+    /// Creates a new vector from its components
+    pub fn new(components: Vec<T>) -> Self {
+        VecN { components }
+    }
+    
+    /// Returns the number of dimensions
+    pub fn dim(&self) -> usize {
+        self.components.len()
+    }
+    
+    /// Ensures both vectors have the same number of dimensions
+    fn check_dim(&self, other: &VecN<T>) -> MathResult<()> {
+        if self.dim() != other.dim() {
+            Err(MathError::InvalidInput(format!(
+                "dimension mismatch: {} vs {}",
+                self.dim(),
+                other.dim()
+            )))
+        } else {
+            Ok(())
+        }
+    }
+    
+    /// Adds another vector element-wise
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::InvalidInput` if the dimensions differ
+    pub fn add(&self, other: &VecN<T>) -> MathResult<VecN<T>> {
+        self.check_dim(other)?;
+        Ok(VecN::new(
+            self.components.iter().zip(&other.components).map(|(&a, &b)| a + b).collect(),
+        ))
+    }
+    
+    /// Subtracts another vector element-wise
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::InvalidInput` if the dimensions differ
+    pub fn sub(&self, other: &VecN<T>) -> MathResult<VecN<T>> {
+        self.check_dim(other)?;
+        Ok(VecN::new(
+            self.components.iter().zip(&other.components).map(|(&a, &b)| a - b).collect(),
+        ))
+    }
+    
+    /// Multiplies every component by a scalar
+    pub fn scale(&self, factor: T) -> VecN<T> {
+        VecN::new(self.components.iter().map(|&a| a * factor).collect())
+    }
+    
+    /// Calculates the dot product with another vector
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::InvalidInput` if the dimensions differ
+    pub fn dot(&self, other: &VecN<T>) -> MathResult<T> {
+        self.check_dim(other)?;
+        Ok(self.components
+            .iter()
+            .zip(&other.components)
+            .fold(T::zero(), |acc, (&a, &b)| acc + a * b))
+    }
+}
+
+impl VecN<f64> {
+    /// Calculates the Euclidean norm (length) of the vector
+    pub fn norm(&self) -> f64 {
+        self.components.iter().map(|x| x * x).sum::<f64>().sqrt()
+    }
+    
+    /// Calculates the Euclidean distance to another point
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::InvalidInput` if the dimensions differ
+    pub fn distance(&self, other: &VecN<f64>) -> MathResult<f64> {
+        Ok(self.sub(other)?.norm())
+    }
+    
+    /// Calculates the cosine of the angle between two vectors
+    ///
+    /// The result is clamped to `[-1, 1]` so rounding error can never push it
+    /// outside the valid range of `acos`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::InvalidInput` if the dimensions differ
+    /// Returns `MathError::DivisionByZero` if either vector has zero length
+    pub fn cosine_similarity(&self, other: &VecN<f64>) -> MathResult<f64> {
+        let dot = self.dot(other)?;
+        let magnitudes = self.norm() * other.norm();
+        if magnitudes == 0.0 {
+            return Err(MathError::DivisionByZero);
+        }
+        Ok((dot / magnitudes).clamp(-1.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    
+    #[test]
+    fn test_add_sub_scale() {
+        let a = VecN::new(vec![1, 2, 3]);
+        let b = VecN::new(vec![4, 5, 6]);
+        
+        assert_eq!(a.add(&b).unwrap(), VecN::new(vec![5, 7, 9]));
+        assert_eq!(b.sub(&a).unwrap(), VecN::new(vec![3, 3, 3]));
+        assert_eq!(a.scale(2), VecN::new(vec![2, 4, 6]));
+        assert_eq!(a.dot(&b).unwrap(), 32);
+    }
+    
+    #[test]
+    fn test_dimension_mismatch() {
+        let a = VecN::new(vec![1.0, 2.0, 3.0]);
+        let b = VecN::new(vec![1.0, 2.0]);
+        
+        assert!(matches!(a.add(&b), Err(MathError::InvalidInput(_))));
+        assert!(matches!(a.sub(&b), Err(MathError::InvalidInput(_))));
+        assert!(matches!(a.dot(&b), Err(MathError::InvalidInput(_))));
+        assert!(matches!(a.distance(&b), Err(MathError::InvalidInput(_))));
+    }
+    
+    #[test]
+    fn test_norm_and_distance() {
+        assert_relative_eq!(VecN::new(vec![3.0, 4.0]).norm(), 5.0);
+        
+        let p = VecN::new(vec![1.0, 2.0, 3.0, 4.0]);
+        let q = VecN::new(vec![2.0, 3.0, 4.0, 5.0]);
+        assert_relative_eq!(p.distance(&q).unwrap(), 2.0);
+    }
+    
+    #[test]
+    fn test_cosine_similarity() {
+        let a = VecN::new(vec![1.0, 2.0, 3.0]);
+        assert_relative_eq!(a.cosine_similarity(&a).unwrap(), 1.0);
+        assert_relative_eq!(a.cosine_similarity(&a.scale(-2.0)).unwrap(), -1.0);
+        
+        let x = VecN::new(vec![1.0, 0.0]);
+        let y = VecN::new(vec![0.0, 5.0]);
+        assert_relative_eq!(x.cosine_similarity(&y).unwrap(), 0.0);
+        
+        // Nearly parallel vectors must not drift above 1.0
+        let b = VecN::new(vec![0.1, 0.2, 0.3]);
+        assert!(b.cosine_similarity(&b.scale(3.0)).unwrap() <= 1.0);
+        
+        let zero = VecN::new(vec![0.0, 0.0]);
+        assert!(matches!(x.cosine_similarity(&zero), Err(MathError::DivisionByZero)));
+        assert!(matches!(a.cosine_similarity(&x), Err(MathError::InvalidInput(_))));
+    }
+} 
\ No newline at end of file
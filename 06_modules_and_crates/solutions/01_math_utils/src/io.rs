@@ -0,0 +1,120 @@
+//! Input/output module
+//!
+//! This module provides helpers for loading numeric data from CSV files
+//! so it can be fed straight into the statistics functions.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use crate::{MathError, MathResult};
+
+/// Reads a zero-based column of `f64` values from a CSV file
+///
+/// Set `has_header` to skip the first line. Blank lines are ignored.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use math_utils::io::read_f64_column;
+/// use math_utils::statistics::mean;
+///
+/// let prices = read_f64_column(Path::new("prices.csv"), 1, true).unwrap();
+/// println!("average price: {:?}", mean(&prices));
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::Io` if the file cannot be read
+/// Returns `MathError::InvalidInput` if a line lacks the column or the value
+/// cannot be parsed, naming the offending line number
+pub fn read_f64_column(path: &Path, column: usize, has_header: bool) -> MathResult<Vec<f64>> {
+    let file = File::open(path)?;
+    parse_f64_column(BufReader::new(file), column, has_header)
+}
+
+/// Parses a zero-based column of `f64` values from CSV data in any reader
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::io::parse_f64_column;
+/// let csv = "name,score\nalice,3.5\nbob,4.0\n";
+/// let scores = parse_f64_column(csv.as_bytes(), 1, true).unwrap();
+/// assert_eq!(scores, vec![3.5, 4.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`read_f64_column`]
+pub fn parse_f64_column<R: BufRead>(reader: R, column: usize, has_header: bool) -> MathResult<Vec<f64>> {
+    let mut values = Vec::new();
+    
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        let line_number = index + 1;
+        
+        if (has_header && index == 0) || line.trim().is_empty() {
+            continue;
+        }
+        
+        let field = line.split(',').nth(column).ok_or_else(|| {
+            MathError::InvalidInput(format!("line {}: missing column {}", line_number, column))
+        })?;
+        
+        let value = field.trim().parse::<f64>().map_err(|e| {
+            MathError::InvalidInput(format!("line {}: invalid number {:?}: {}", line_number, field.trim(), e))
+        })?;
+        
+        values.push(value);
+    }
+    
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    
+    #[test]
+    fn test_parse_f64_column() {
+        let csv = "city,temp,humidity\nOslo,4.5,80\nCairo,31.0,20\n\nLima,19.25,70\n";
+        
+        let temps = parse_f64_column(Cursor::new(csv), 1, true).unwrap();
+        assert_eq!(temps, vec![4.5, 31.0, 19.25]);
+        
+        let humidity = parse_f64_column(Cursor::new(csv), 2, true).unwrap();
+        assert_eq!(humidity, vec![80.0, 20.0, 70.0]);
+    }
+    
+    #[test]
+    fn test_parse_f64_column_errors() {
+        let csv = "1.0,2.0\n3.0,oops\n";
+        match parse_f64_column(Cursor::new(csv), 1, false) {
+            Err(MathError::InvalidInput(msg)) => assert!(msg.starts_with("line 2:")),
+            other => panic!("expected InvalidInput, got {:?}", other),
+        }
+        
+        let csv = "1.0,2.0\n3.0\n";
+        assert!(matches!(
+            parse_f64_column(Cursor::new(csv), 1, false),
+            Err(MathError::InvalidInput(_))
+        ));
+        
+        // Without skipping it, the header is reported as a bad value
+        let csv = "a,b\n1.0,2.0\n";
+        assert!(parse_f64_column(Cursor::new(csv), 0, false).is_err());
+    }
+    
+    #[test]
+    fn test_read_f64_column() {
+        let path = std::env::temp_dir().join("math_utils_read_f64_column.csv");
+        std::fs::write(&path, "x,y\n1,2\n3,4\n").unwrap();
+        
+        assert_eq!(read_f64_column(&path, 1, true).unwrap(), vec![2.0, 4.0]);
+        std::fs::remove_file(&path).unwrap();
+        
+        assert!(matches!(read_f64_column(&path, 1, true), Err(MathError::Io(_))));
+    }
+} 
\ No newline at end of file
@@ -1,13 +1,18 @@
 //! Math Utilities Library
 //!
 //! This library provides a comprehensive set of mathematical utilities
-//! organized into modules for arithmetic, statistics, and geometry.
+//! organized into modules for arithmetic, algebra, statistics, geometry, and text.
 //!
 //! # Features
 //!
 //! - Basic arithmetic operations (GCD, LCM, etc.)
+//! - Polynomial evaluation and differentiation
 //! - Statistical functions (mean, median, mode, etc.)
 //! - Geometric calculations (areas, perimeters, transformations)
+//! - Loading numeric data from CSV files
+//! - Type-checked temperature units
+//! - Seedable random selection helpers
+//! - Text utilities (run-length encoding, Caesar cipher, edit distance, Soundex)
 //!
 //! # Examples
 //!
@@ -32,9 +37,14 @@ use std::error::Error;
 use std::fmt;
 use thiserror::Error;
 
+pub mod algebra;
 pub mod arithmetic;
 pub mod statistics;
 pub mod geometry;
+pub mod io;
+pub mod random;
+pub mod text;
+pub mod units;
 
 /// Common error type for math operations
 #[derive(Error, Debug)]
@@ -47,6 +57,8 @@ pub enum MathError {
     OutOfRange(String),
     #[error("empty data set")]
     EmptyDataSet,
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
 }
 
 /// Result type for math operations
@@ -57,6 +69,33 @@ pub trait Number: num_traits::Num + Copy + PartialOrd + fmt::Debug {}
 
 impl<T> Number for T where T: num_traits::Num + Copy + PartialOrd + fmt::Debug {}
 
+/// Splits an iterator of results into its successes and its failures.
+///
+/// Unlike collecting into `Result<Vec<T>, E>`, this does not stop at the first
+/// error, so callers can report every failure or carry on with the good values.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::partition_results;
+///
+/// let parsed = ["1", "x", "3"].iter().map(|s| s.parse::<i32>());
+/// let (numbers, errors) = partition_results(parsed);
+/// assert_eq!(numbers, vec![1, 3]);
+/// assert_eq!(errors.len(), 1);
+/// ```
+pub fn partition_results<T, E>(items: impl Iterator<Item = Result<T, E>>) -> (Vec<T>, Vec<E>) {
+    let mut successes = Vec::new();
+    let mut failures = Vec::new();
+    for item in items {
+        match item {
+            Ok(value) => successes.push(value),
+            Err(err) => failures.push(err),
+        }
+    }
+    (successes, failures)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -80,4 +119,23 @@ mod tests {
         assert_eq!(accepts_number(1, 2), 3);
         assert_eq!(accepts_number(1.5, 2.5), 4.0);
     }
+
+    #[test]
+    fn test_partition_results() {
+        let items = vec![
+            Ok(1),
+            Err(MathError::DivisionByZero),
+            Ok(2),
+            Err(MathError::EmptyDataSet),
+            Ok(3),
+        ];
+        let (successes, failures) = partition_results(items.into_iter());
+        assert_eq!(successes, vec![1, 2, 3]);
+        assert_eq!(failures.len(), 2);
+        assert!(matches!(failures[0], MathError::DivisionByZero));
+        assert!(matches!(failures[1], MathError::EmptyDataSet));
+
+        let (successes, failures) = partition_results(std::iter::empty::<Result<i32, String>>());
+        assert!(successes.is_empty() && failures.is_empty());
+    }
 } 
\ No newline at end of file
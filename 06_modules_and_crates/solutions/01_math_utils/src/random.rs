@@ -0,0 +1,231 @@
+//! Random utilities module
+//!
+//! This module provides random selection helpers and a small deterministic
+//! generator. Every function takes the random number generator explicitly,
+//! so callers can pass a seeded generator to get reproducible results in tests.
+
+use rand::{Rng, RngCore};
+use crate::{MathError, MathResult};
+
+/// A tiny, fast, deterministic pseudo-random generator (SplitMix64)
+///
+/// Not suitable for cryptography. It implements `rand::RngCore`, so it can
+/// be passed anywhere a `rand` generator is expected.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::random::SplitMix64;
+///
+/// let mut a = SplitMix64::new(2024);
+/// let mut b = SplitMix64::new(2024);
+/// assert_eq!(a.next_u64(), b.next_u64());
+///
+/// let roll = a.gen_range(1, 7);
+/// assert!((1..7).contains(&roll));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Creates a new generator from a seed
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+    
+    /// Returns the next pseudo-random `u64`
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+    
+    /// Returns a uniformly distributed value in `[low, high)`
+    ///
+    /// This shadows `rand::Rng::gen_range`; call that as
+    /// `Rng::gen_range(&mut rng, range)` if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low >= high`
+    pub fn gen_range(&mut self, low: u64, high: u64) -> u64 {
+        assert!(low < high, "gen_range requires low < high");
+        let range = high - low;
+        
+        // Reject the few values that would bias the modulo towards low numbers
+        let threshold = range.wrapping_neg() % range;
+        loop {
+            let x = self.next_u64();
+            if x >= threshold {
+                return low + x % range;
+            }
+        }
+    }
+}
+
+impl RngCore for SplitMix64 {
+    fn next_u32(&mut self) -> u32 {
+        (SplitMix64::next_u64(self) >> 32) as u32
+    }
+    
+    fn next_u64(&mut self) -> u64 {
+        SplitMix64::next_u64(self)
+    }
+    
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = SplitMix64::next_u64(self).to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+    
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Picks an item with probability proportional to its weight
+///
+/// # Examples
+///
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use math_utils::random::weighted_choice;
+///
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let loot = [("common", 90.0), ("rare", 9.0), ("legendary", 1.0)];
+/// let drop = weighted_choice(&loot, &mut rng).unwrap();
+/// assert!(["common", "rare", "legendary"].contains(&drop));
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if `items` is empty, any weight is
+/// negative or not finite, or the weights sum to zero
+pub fn weighted_choice<T: Clone>(items: &[(T, f64)], rng: &mut impl Rng) -> MathResult<T> {
+    if items.is_empty() {
+        return Err(MathError::InvalidInput("no items to choose from".to_string()));
+    }
+    
+    if items.iter().any(|(_, w)| !w.is_finite() || *w < 0.0) {
+        return Err(MathError::InvalidInput("weights must be finite and non-negative".to_string()));
+    }
+    
+    let total: f64 = items.iter().map(|(_, w)| w).sum();
+    if total <= 0.0 {
+        return Err(MathError::InvalidInput("weights must sum to more than zero".to_string()));
+    }
+    
+    let mut target = rng.gen::<f64>() * total;
+    for (item, weight) in items {
+        if target < *weight {
+            return Ok(item.clone());
+        }
+        target -= weight;
+    }
+    
+    // Rounding can leave `target` just past the last bucket; fall back to
+    // the last item that can actually be chosen
+    let (item, _) = items.iter().rev().find(|(_, w)| *w > 0.0).unwrap();
+    Ok(item.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    
+    #[test]
+    fn test_weighted_choice_is_deterministic_with_seed() {
+        let items = [('a', 1.0), ('b', 2.0), ('c', 3.0)];
+        
+        let picks: Vec<char> = {
+            let mut rng = StdRng::seed_from_u64(42);
+            (0..5).map(|_| weighted_choice(&items, &mut rng).unwrap()).collect()
+        };
+        let again: Vec<char> = {
+            let mut rng = StdRng::seed_from_u64(42);
+            (0..5).map(|_| weighted_choice(&items, &mut rng).unwrap()).collect()
+        };
+        
+        assert_eq!(picks, again);
+        assert_eq!(picks, vec!['c', 'c', 'c', 'b', 'a']);
+    }
+    
+    #[test]
+    fn test_weighted_choice_respects_weights() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let items = [("never", 0.0), ("always", 5.0), ("also never", 0.0)];
+        for _ in 0..100 {
+            assert_eq!(weighted_choice(&items, &mut rng).unwrap(), "always");
+        }
+        
+        let items = [("heavy", 99.0), ("light", 1.0)];
+        let heavy = (0..1000)
+            .filter(|_| weighted_choice(&items, &mut rng).unwrap() == "heavy")
+            .count();
+        assert!(heavy > 950);
+    }
+    
+    #[test]
+    fn test_weighted_choice_invalid_input() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let empty: [(i32, f64); 0] = [];
+        
+        assert!(matches!(weighted_choice(&empty, &mut rng), Err(MathError::InvalidInput(_))));
+        assert!(matches!(weighted_choice(&[(1, -1.0), (2, 3.0)], &mut rng), Err(MathError::InvalidInput(_))));
+        assert!(matches!(weighted_choice(&[(1, 0.0), (2, 0.0)], &mut rng), Err(MathError::InvalidInput(_))));
+        assert!(matches!(weighted_choice(&[(1, f64::NAN)], &mut rng), Err(MathError::InvalidInput(_))));
+    }
+    
+    #[test]
+    fn test_split_mix_64_reference_values() {
+        let mut rng = SplitMix64::new(1234567);
+        assert_eq!(rng.next_u64(), 6457827717110365317);
+        assert_eq!(rng.next_u64(), 3203168211198807973);
+        assert_eq!(rng.next_u64(), 9817491932198370423);
+    }
+    
+    #[test]
+    fn test_split_mix_64_same_seed_same_sequence() {
+        let mut a = SplitMix64::new(99);
+        let mut b = SplitMix64::new(99);
+        let mut c = SplitMix64::new(100);
+        
+        let seq_a: Vec<u64> = (0..10).map(|_| a.next_u64()).collect();
+        let seq_b: Vec<u64> = (0..10).map(|_| b.next_u64()).collect();
+        let seq_c: Vec<u64> = (0..10).map(|_| c.next_u64()).collect();
+        
+        assert_eq!(seq_a, seq_b);
+        assert_ne!(seq_a, seq_c);
+    }
+    
+    #[test]
+    fn test_split_mix_64_gen_range_and_rng_core() {
+        let mut rng = SplitMix64::new(5);
+        for _ in 0..1000 {
+            let x = rng.gen_range(10, 20);
+            assert!((10..20).contains(&x));
+        }
+        
+        let mut bytes = [0u8; 13];
+        rng.fill_bytes(&mut bytes);
+        assert!(bytes.iter().any(|&b| b != 0));
+        
+        // Works as a drop-in generator for other helpers
+        let mut a = SplitMix64::new(3);
+        let mut b = SplitMix64::new(3);
+        let items = [(1, 1.0), (2, 1.0), (3, 1.0)];
+        assert_eq!(
+            weighted_choice(&items, &mut a).unwrap(),
+            weighted_choice(&items, &mut b).unwrap()
+        );
+    }
+} 
\ No newline at end of file
@@ -3,10 +3,19 @@
 //! This module provides statistical functions for analyzing numerical data.
 
 use std::collections::HashMap;
+use std::hash::Hash;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::geometry::vecn::VecN;
 use crate::{MathError, MathResult, Number};
 
 /// Calculates the mean (average) of a sequence of numbers
 ///
+/// The sum is accumulated naively, so with many values of very different
+/// magnitudes the small ones can be lost to rounding. For `f64` data where
+/// that matters, use [`mean_stable`], which trades a few extra operations
+/// per element for compensated summation.
+///
 /// # Examples
 ///
 /// ```
@@ -26,6 +35,54 @@ where
     Some(sum / T::from(numbers.len()).unwrap())
 }
 
+/// Sums a sequence of numbers using Kahan (compensated) summation
+///
+/// A running compensation term captures the low-order bits lost on each
+/// addition and feeds them back into the next one, so the rounding error
+/// stays bounded instead of growing with the number of elements.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::kahan_sum;
+/// let mut data = vec![1.0];
+/// data.extend(std::iter::repeat_n(1e-16, 10));
+/// assert!(kahan_sum(&data) > 1.0);
+/// ```
+pub fn kahan_sum(data: &[f64]) -> f64 {
+    let mut sum = 0.0;
+    let mut compensation = 0.0;
+    
+    for &x in data {
+        let y = x - compensation;
+        let t = sum + y;
+        compensation = (t - sum) - y;
+        sum = t;
+    }
+    
+    sum
+}
+
+/// Calculates the mean of a sequence of numbers using [`kahan_sum`]
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::mean_stable;
+/// assert_eq!(mean_stable(&[1.0, 2.0, 3.0]).unwrap(), 2.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+pub fn mean_stable(data: &[f64]) -> MathResult<f64> {
+    if data.is_empty() {
+        return Err(MathError::EmptyDataSet);
+    }
+    
+    Ok(kahan_sum(data) / data.len() as f64)
+}
+
 /// Calculates the median of a sequence of numbers
 ///
 /// # Examples
@@ -80,6 +137,59 @@ where
         .map(|(val, _)| val)
 }
 
+/// Finds the most frequent value(s) of a float data set along with how often
+/// they occur
+///
+/// Values are grouped by their exact bit pattern rather than by `==`, so no
+/// rounding is applied: `0.1 + 0.2` and `0.3` are different values. Two cases
+/// are normalized first: `-0.0` counts as `0.0`, and every NaN counts as the
+/// same value. When several values share the highest count, all of them are
+/// returned in order of first appearance.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::mode_with_count;
+/// let data = [1.0, 2.0, 2.0, 3.0, 3.0];
+/// assert_eq!(mode_with_count(&data).unwrap(), (vec![2.0, 3.0], 2));
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if `data` is empty
+pub fn mode_with_count(data: &[f64]) -> MathResult<(Vec<f64>, usize)> {
+    if data.is_empty() {
+        return Err(MathError::EmptyDataSet);
+    }
+    
+    let key = |x: f64| -> u64 {
+        if x.is_nan() {
+            f64::NAN.to_bits()
+        } else if x == 0.0 {
+            0.0f64.to_bits()
+        } else {
+            x.to_bits()
+        }
+    };
+    
+    // Each entry holds (count, index of first appearance)
+    let mut counts: HashMap<u64, (usize, usize)> = HashMap::new();
+    for (index, &x) in data.iter().enumerate() {
+        counts.entry(key(x)).or_insert((0, index)).0 += 1;
+    }
+    
+    let max_count = counts.values().map(|&(count, _)| count).max().unwrap_or(0);
+    let mut modal: Vec<usize> = counts
+        .into_values()
+        .filter(|&(count, _)| count == max_count)
+        .map(|(_, first)| first)
+        .collect();
+    modal.sort_unstable();
+    
+    let values = modal.into_iter().map(|i| f64::from_bits(key(data[i]))).collect();
+    Ok((values, max_count))
+}
+
 /// Calculates the variance of a sequence of numbers
 ///
 /// # Examples
@@ -174,6 +284,762 @@ where
     Ok(T::from(correlation))
 }
 
+/// Ensures the data is non-empty and free of NaN values
+fn check_ordered_data(data: &[f64]) -> MathResult<()> {
+    if data.is_empty() {
+        return Err(MathError::EmptyDataSet);
+    }
+    
+    if data.iter().any(|x| x.is_nan()) {
+        return Err(MathError::InvalidInput("data contains NaN".to_string()));
+    }
+    
+    Ok(())
+}
+
+/// Finds the smallest value in a sequence of numbers
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::min;
+/// assert_eq!(min(&[3.0, -1.5, 2.0]).unwrap(), -1.5);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::InvalidInput` if the data contains NaN
+pub fn min(data: &[f64]) -> MathResult<f64> {
+    check_ordered_data(data)?;
+    
+    Ok(data.iter()
+        .cloned()
+        .min_by(|a, b| a.partial_cmp(b).unwrap())
+        .unwrap())
+}
+
+/// Finds the largest value in a sequence of numbers
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::max;
+/// assert_eq!(max(&[3.0, -1.5, 2.0]).unwrap(), 3.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::InvalidInput` if the data contains NaN
+pub fn max(data: &[f64]) -> MathResult<f64> {
+    check_ordered_data(data)?;
+    
+    Ok(data.iter()
+        .cloned()
+        .max_by(|a, b| a.partial_cmp(b).unwrap())
+        .unwrap())
+}
+
+/// Calculates the range (max minus min) of a sequence of numbers
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::range;
+/// assert_eq!(range(&[3.0, -1.5, 2.0]).unwrap(), 4.5);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::InvalidInput` if the data contains NaN
+pub fn range(data: &[f64]) -> MathResult<f64> {
+    Ok(max(data)? - min(data)?)
+}
+
+/// Interpolates the `p`-th quantile (0.0 to 1.0) of already sorted data
+fn quantile_sorted(sorted: &[f64], p: f64) -> f64 {
+    let position = p * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+/// Calculates the first, second (median) and third quartiles
+///
+/// Uses linear interpolation between the closest ranks.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::quartiles;
+/// let (q1, q2, q3) = quartiles(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+/// assert_eq!((q1, q2, q3), (2.0, 3.0, 4.0));
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::InvalidInput` if the data contains NaN
+pub fn quartiles(data: &[f64]) -> MathResult<(f64, f64, f64)> {
+    check_ordered_data(data)?;
+    
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    
+    Ok((
+        quantile_sorted(&sorted, 0.25),
+        quantile_sorted(&sorted, 0.5),
+        quantile_sorted(&sorted, 0.75),
+    ))
+}
+
+/// Calculates the inclusive bounds outside which values count as outliers
+/// under the 1.5 × IQR rule
+fn outlier_fences(data: &[f64]) -> MathResult<(f64, f64)> {
+    let (q1, _, q3) = quartiles(data)?;
+    let iqr = q3 - q1;
+    Ok((q1 - 1.5 * iqr, q3 + 1.5 * iqr))
+}
+
+/// Finds values below `Q1 - 1.5 * IQR` or above `Q3 + 1.5 * IQR`
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::outliers;
+/// let data = vec![10.0, 12.0, 11.0, 13.0, 1000.0];
+/// assert_eq!(outliers(&data).unwrap(), vec![1000.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::InvalidInput` if the data contains NaN
+pub fn outliers(data: &[f64]) -> MathResult<Vec<f64>> {
+    let (low, high) = outlier_fences(data)?;
+    Ok(data.iter().cloned().filter(|&x| x < low || x > high).collect())
+}
+
+/// Returns a copy of the data with IQR outliers removed, preserving order
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::remove_outliers;
+/// let data = vec![10.0, 1000.0, 12.0, 11.0, 13.0];
+/// assert_eq!(remove_outliers(&data).unwrap(), vec![10.0, 12.0, 11.0, 13.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::InvalidInput` if the data contains NaN
+pub fn remove_outliers(data: &[f64]) -> MathResult<Vec<f64>> {
+    let (low, high) = outlier_fences(data)?;
+    Ok(data.iter().cloned().filter(|&x| x >= low && x <= high).collect())
+}
+
+/// Calculates the mean after discarding a fraction of the data from each end
+///
+/// `trim_fraction` of the values (rounded down) is dropped from both the
+/// low and the high end of the sorted data before averaging, which limits
+/// the influence of extreme values.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::trimmed_mean;
+/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 100.0];
+/// assert_eq!(trimmed_mean(&data, 0.2).unwrap(), 3.5);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::InvalidInput` if the data contains NaN
+/// Returns `MathError::OutOfRange` if `trim_fraction` is not within `[0, 0.5)`
+pub fn trimmed_mean(data: &[f64], trim_fraction: f64) -> MathResult<f64> {
+    if !(0.0..0.5).contains(&trim_fraction) {
+        return Err(MathError::OutOfRange(format!(
+            "trim fraction {} must be within [0, 0.5)",
+            trim_fraction
+        )));
+    }
+    check_ordered_data(data)?;
+    
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    
+    // Below one half, at least one value always survives the trim
+    let cut = (data.len() as f64 * trim_fraction).floor() as usize;
+    let kept = &sorted[cut..sorted.len() - cut];
+    Ok(kept.iter().sum::<f64>() / kept.len() as f64)
+}
+
+/// Calculates the geometric mean of a sequence of positive numbers
+///
+/// Computed as the exponential of the mean of logarithms, which avoids
+/// overflowing the running product on large inputs.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::geometric_mean;
+/// assert!((geometric_mean(&[1.0, 4.0]).unwrap() - 2.0).abs() < 1e-10);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::InvalidInput` if any value is not positive
+pub fn geometric_mean(data: &[f64]) -> MathResult<f64> {
+    if data.is_empty() {
+        return Err(MathError::EmptyDataSet);
+    }
+    
+    if data.iter().any(|&x| x <= 0.0 || x.is_nan()) {
+        return Err(MathError::InvalidInput("values must be positive".to_string()));
+    }
+    
+    let log_sum: f64 = data.iter().map(|x| x.ln()).sum();
+    Ok((log_sum / data.len() as f64).exp())
+}
+
+/// Calculates the harmonic mean of a sequence of numbers
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::harmonic_mean;
+/// assert!((harmonic_mean(&[1.0, 2.0, 4.0]).unwrap() - 12.0 / 7.0).abs() < 1e-10);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::DivisionByZero` if any value is zero
+pub fn harmonic_mean(data: &[f64]) -> MathResult<f64> {
+    if data.is_empty() {
+        return Err(MathError::EmptyDataSet);
+    }
+    
+    if data.contains(&0.0) {
+        return Err(MathError::DivisionByZero);
+    }
+    
+    let reciprocal_sum: f64 = data.iter().map(|x| 1.0 / x).sum();
+    Ok(data.len() as f64 / reciprocal_sum)
+}
+
+/// Standardizes each value as `(x - mean) / s` using the sample standard
+/// deviation `s`
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::z_scores;
+/// let scores = z_scores(&[2.0, 4.0, 6.0]).unwrap();
+/// assert_eq!(scores, vec![-1.0, 0.0, 1.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::DivisionByZero` if the standard deviation is zero,
+/// including when there is only a single value
+pub fn z_scores(data: &[f64]) -> MathResult<Vec<f64>> {
+    if data.is_empty() {
+        return Err(MathError::EmptyDataSet);
+    }
+    
+    if data.len() < 2 {
+        return Err(MathError::DivisionByZero);
+    }
+    
+    let m = mean(data).unwrap();
+    let sum_squares: f64 = data.iter().map(|x| (x - m) * (x - m)).sum();
+    let std_dev = (sum_squares / (data.len() - 1) as f64).sqrt();
+    
+    if std_dev == 0.0 {
+        return Err(MathError::DivisionByZero);
+    }
+    
+    Ok(data.iter().map(|x| (x - m) / std_dev).collect())
+}
+
+/// Rescales the data linearly so the minimum maps to 0.0 and the maximum to 1.0
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::min_max_normalize;
+/// let scaled = min_max_normalize(&[10.0, 15.0, 20.0]).unwrap();
+/// assert_eq!(scaled, vec![0.0, 0.5, 1.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::InvalidInput` if the data contains NaN
+/// Returns `MathError::DivisionByZero` if all values are equal
+pub fn min_max_normalize(data: &[f64]) -> MathResult<Vec<f64>> {
+    let low = min(data)?;
+    let span = max(data)? - low;
+    
+    if span == 0.0 {
+        return Err(MathError::DivisionByZero);
+    }
+    
+    Ok(data.iter().map(|x| (x - low) / span).collect())
+}
+
+/// Computes the variance and the `k`-th population central moment after
+/// checking there are at least `min_len` values and non-zero variance
+fn central_moment(data: &[f64], k: i32, min_len: usize, name: &str) -> MathResult<(f64, f64)> {
+    if data.is_empty() {
+        return Err(MathError::EmptyDataSet);
+    }
+    if data.len() < min_len {
+        return Err(MathError::InvalidInput(format!(
+            "{} requires at least {} values, got {}",
+            name,
+            min_len,
+            data.len()
+        )));
+    }
+    
+    let var = variance(data)?;
+    if var == 0.0 {
+        return Err(MathError::DivisionByZero);
+    }
+    
+    let m = mean(data).unwrap();
+    let moment = data.iter().map(|x| (x - m).powi(k)).sum::<f64>() / data.len() as f64;
+    Ok((var, moment))
+}
+
+/// Calculates the Fisher-Pearson coefficient of skewness
+///
+/// This is the third central moment divided by the variance raised to 3/2.
+/// Symmetric data has a skewness of zero; a long right tail makes it positive.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::skewness;
+/// assert!(skewness(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap().abs() < 1e-10);
+/// assert!(skewness(&[1.0, 1.0, 1.0, 10.0]).unwrap() > 0.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::InvalidInput` if there are fewer than 3 values
+/// Returns `MathError::DivisionByZero` if all values are equal
+pub fn skewness(data: &[f64]) -> MathResult<f64> {
+    let (var, third) = central_moment(data, 3, 3, "skewness")?;
+    Ok(third / var.powf(1.5))
+}
+
+/// Calculates the excess kurtosis
+///
+/// This is the fourth central moment divided by the squared variance, minus 3
+/// so that a normal distribution scores zero. Heavy tails give positive values.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::kurtosis;
+/// // Two equally likely values are as light-tailed as possible
+/// assert!((kurtosis(&[0.0, 1.0, 0.0, 1.0]).unwrap() + 2.0).abs() < 1e-10);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::InvalidInput` if there are fewer than 4 values
+/// Returns `MathError::DivisionByZero` if all values are equal
+pub fn kurtosis(data: &[f64]) -> MathResult<f64> {
+    let (var, fourth) = central_moment(data, 4, 4, "kurtosis")?;
+    Ok(fourth / (var * var) - 3.0)
+}
+
+/// Applies `f` to every contiguous window of `window` elements
+///
+/// This is the general building block for rolling statistics such as a
+/// rolling median or rolling maximum.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::windows_map;
+/// let data = vec![1, 2, 3, 4, 5];
+/// let sums = windows_map(&data, 3, |w| w.iter().sum::<i32>()).unwrap();
+/// assert_eq!(sums, vec![6, 9, 12]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if `window` is zero or larger than the data
+pub fn windows_map<T, R, F>(data: &[T], window: usize, f: F) -> MathResult<Vec<R>>
+where
+    F: Fn(&[T]) -> R,
+{
+    Ok(windows_iter(data, window)?.map(f).collect())
+}
+
+/// Lazily iterates over every contiguous window of `size` elements
+///
+/// Unlike `slice::windows`, an oversized or zero window is reported as an
+/// error rather than yielding nothing or panicking.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::windows_iter;
+/// let mut windows = windows_iter(&[1, 2, 3], 2).unwrap();
+/// assert_eq!(windows.next(), Some(&[1, 2][..]));
+/// assert_eq!(windows.next(), Some(&[2, 3][..]));
+/// assert_eq!(windows.next(), None);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if `size` is zero or larger than the data
+pub fn windows_iter<T>(data: &[T], size: usize) -> MathResult<std::slice::Windows<'_, T>> {
+    if size == 0 || size > data.len() {
+        return Err(MathError::InvalidInput(format!(
+            "window size {} is invalid for {} elements",
+            size,
+            data.len()
+        )));
+    }
+    
+    Ok(data.windows(size))
+}
+
+/// Collects every contiguous window of `size` elements into owned vectors
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::windows_owned;
+/// let windows = windows_owned(&[1, 2, 3, 4], 2).unwrap();
+/// assert_eq!(windows, vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if `size` is zero or larger than the data
+pub fn windows_owned<T: Clone>(data: &[T], size: usize) -> MathResult<Vec<Vec<T>>> {
+    Ok(windows_iter(data, size)?.map(<[T]>::to_vec).collect())
+}
+
+/// Calculates the simple moving average over windows of `window` elements
+///
+/// Returns one value per fully covered window, so the output has
+/// `data.len() - window + 1` elements. Uses a running sum, making it O(n)
+/// regardless of the window size.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::moving_average;
+/// let smoothed = moving_average(&[1.0, 2.0, 3.0, 4.0, 5.0], 3).unwrap();
+/// assert_eq!(smoothed, vec![2.0, 3.0, 4.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if `window` is zero or larger than the data
+pub fn moving_average(data: &[f64], window: usize) -> MathResult<Vec<f64>> {
+    if window == 0 || window > data.len() {
+        return Err(MathError::InvalidInput(format!(
+            "window size {} is invalid for {} elements",
+            window,
+            data.len()
+        )));
+    }
+    
+    let mut sum: f64 = data[..window].iter().sum();
+    let mut averages = Vec::with_capacity(data.len() - window + 1);
+    averages.push(sum / window as f64);
+    
+    for i in window..data.len() {
+        sum += data[i] - data[i - window];
+        averages.push(sum / window as f64);
+    }
+    
+    Ok(averages)
+}
+
+/// Calculates the exponential moving average of a series
+///
+/// Follows `s[t] = alpha * x[t] + (1 - alpha) * s[t - 1]`, seeded with the
+/// first data point, so the output has the same length as the input.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::ema;
+/// let smoothed = ema(&[2.0, 4.0, 8.0], 0.5).unwrap();
+/// assert_eq!(smoothed, vec![2.0, 3.0, 5.5]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::OutOfRange` if `alpha` is not within `(0, 1]`
+pub fn ema(data: &[f64], alpha: f64) -> MathResult<Vec<f64>> {
+    if !(alpha > 0.0 && alpha <= 1.0) {
+        return Err(MathError::OutOfRange(format!("alpha {} must be within (0, 1]", alpha)));
+    }
+    
+    let (&first, rest) = data.split_first().ok_or(MathError::EmptyDataSet)?;
+    
+    let mut smoothed = Vec::with_capacity(data.len());
+    smoothed.push(first);
+    
+    let mut previous = first;
+    for &x in rest {
+        previous = alpha * x + (1.0 - alpha) * previous;
+        smoothed.push(previous);
+    }
+    
+    Ok(smoothed)
+}
+
+/// A single histogram bin covering `[start, end)`
+///
+/// The last bin also includes its `end` so the maximum value is counted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistogramBin {
+    pub start: f64,
+    pub end: f64,
+    pub count: usize,
+}
+
+/// Groups the data into `bins` equal-width bins spanning its min to max
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::histogram;
+/// let data = vec![1.0, 2.0, 2.5, 4.0];
+/// let bins = histogram(&data, 3).unwrap();
+/// assert_eq!(bins.iter().map(|b| b.count).collect::<Vec<_>>(), vec![1, 2, 1]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::EmptyDataSet` if the data is empty
+/// Returns `MathError::InvalidInput` if `bins` is zero or the data contains
+/// non-finite values
+pub fn histogram(data: &[f64], bins: usize) -> MathResult<Vec<HistogramBin>> {
+    if data.is_empty() {
+        return Err(MathError::EmptyDataSet);
+    }
+    
+    if bins == 0 {
+        return Err(MathError::InvalidInput("bin count must be positive".to_string()));
+    }
+    
+    if data.iter().any(|x| !x.is_finite()) {
+        return Err(MathError::InvalidInput("data must be finite".to_string()));
+    }
+    
+    let min = data.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = data.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let width = (max - min) / bins as f64;
+    
+    let mut result: Vec<HistogramBin> = (0..bins)
+        .map(|i| HistogramBin {
+            start: min + width * i as f64,
+            end: min + width * (i + 1) as f64,
+            count: 0,
+        })
+        .collect();
+    
+    for &x in data {
+        let index = if width == 0.0 {
+            0
+        } else {
+            (((x - min) / width) as usize).min(bins - 1)
+        };
+        result[index].count += 1;
+    }
+    
+    Ok(result)
+}
+
+/// Computes a histogram and serializes it as a JSON array of
+/// `{"start", "end", "count"}` objects
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::histogram_json;
+/// let json = histogram_json(&[1.0, 2.0, 3.0], 2).unwrap();
+/// assert!(json.starts_with("[{\"start\":1.0"));
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`histogram`]
+pub fn histogram_json(data: &[f64], bins: usize) -> MathResult<String> {
+    let bins = histogram(data, bins)?;
+    serde_json::to_string(&bins).map_err(|e| MathError::InvalidInput(e.to_string()))
+}
+
+/// Counts how many times each distinct item occurs
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::frequencies;
+/// let counts = frequencies(&['a', 'b', 'a']);
+/// assert_eq!(counts[&'a'], 2);
+/// assert_eq!(counts[&'b'], 1);
+/// ```
+pub fn frequencies<T: Eq + Hash + Clone>(items: &[T]) -> HashMap<T, usize> {
+    let mut counts = HashMap::new();
+    for item in items {
+        *counts.entry(item.clone()).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Returns the `k` most frequent items with their counts, most frequent first
+///
+/// Items with equal counts are ordered by where they first appear, so the
+/// result is deterministic despite the underlying `HashMap`.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::statistics::most_common;
+/// let words = ["b", "a", "b", "c", "a"];
+/// assert_eq!(most_common(&words, 2), vec![("b", 2), ("a", 2)]);
+/// ```
+pub fn most_common<T: Eq + Hash + Clone>(items: &[T], k: usize) -> Vec<(T, usize)> {
+    // Each entry holds (count, index of first appearance)
+    let mut counts: HashMap<&T, (usize, usize)> = HashMap::new();
+    for (index, item) in items.iter().enumerate() {
+        counts.entry(item).or_insert((0, index)).0 += 1;
+    }
+    
+    let mut ranked: Vec<(&T, (usize, usize))> = counts.into_iter().collect();
+    ranked.sort_by(|(_, (count_a, first_a)), (_, (count_b, first_b))| {
+        count_b.cmp(count_a).then(first_a.cmp(first_b))
+    });
+    ranked
+        .into_iter()
+        .take(k)
+        .map(|(item, (count, _))| (item.clone(), count))
+        .collect()
+}
+
+/// Returns the index of the centroid closest to `point`
+///
+/// Fails like `VecN::distance` if the dimensions differ
+fn nearest_centroid(point: &VecN<f64>, centroids: &[VecN<f64>]) -> MathResult<usize> {
+    let mut nearest = 0;
+    let mut nearest_distance = f64::INFINITY;
+    for (i, centroid) in centroids.iter().enumerate() {
+        let distance = point.distance(centroid)?;
+        if distance < nearest_distance {
+            nearest = i;
+            nearest_distance = distance;
+        }
+    }
+    Ok(nearest)
+}
+
+/// Groups points into `k` clusters using Lloyd's algorithm
+///
+/// The initial centroids are `k` distinct points picked with `rng`, so a
+/// seeded generator gives reproducible results. Iteration stops once no point
+/// changes cluster or after `max_iters` rounds. A cluster that loses all of its
+/// points keeps its previous centroid.
+///
+/// Returns the cluster index (`0..k`) of each point, in input order.
+///
+/// # Examples
+///
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use math_utils::geometry::vecn::VecN;
+/// use math_utils::statistics::kmeans;
+///
+/// let points: Vec<VecN<f64>> = [[0.0, 0.0], [0.5, 0.0], [10.0, 10.0], [10.5, 10.0]]
+///     .iter()
+///     .map(|p| VecN::new(p.to_vec()))
+///     .collect();
+/// let mut rng = StdRng::seed_from_u64(3);
+/// let labels = kmeans(&points, 2, 100, &mut rng).unwrap();
+/// assert_eq!(labels[0], labels[1]);
+/// assert_ne!(labels[0], labels[2]);
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if `k` is zero or larger than the number
+/// of points, or if the points do not all have the same dimension
+pub fn kmeans(points: &[VecN<f64>], k: usize, max_iters: usize, rng: &mut impl Rng) -> MathResult<Vec<usize>> {
+    if k == 0 || k > points.len() {
+        return Err(MathError::InvalidInput(format!(
+            "k must be between 1 and the number of points ({}), got {}",
+            points.len(),
+            k
+        )));
+    }
+    
+    let dim = points[0].dim();
+    if points.iter().any(|p| p.dim() != dim) {
+        return Err(MathError::InvalidInput("all points must have the same dimension".to_string()));
+    }
+    
+    let mut centroids: Vec<VecN<f64>> = rand::seq::index::sample(rng, points.len(), k)
+        .into_iter()
+        .map(|i| points[i].clone())
+        .collect();
+    let mut assignments = points
+        .iter()
+        .map(|p| nearest_centroid(p, &centroids))
+        .collect::<MathResult<Vec<usize>>>()?;
+    
+    for _ in 0..max_iters {
+        // Move each centroid to the mean of its points
+        let mut sums = vec![vec![0.0; dim]; k];
+        let mut counts = vec![0usize; k];
+        for (point, &cluster) in points.iter().zip(&assignments) {
+            for (sum, x) in sums[cluster].iter_mut().zip(&point.components) {
+                *sum += x;
+            }
+            counts[cluster] += 1;
+        }
+        for ((centroid, sum), count) in centroids.iter_mut().zip(sums).zip(counts) {
+            if count > 0 {
+                *centroid = VecN::new(sum.into_iter().map(|x| x / count as f64).collect());
+            }
+        }
+        
+        let next = points
+            .iter()
+            .map(|p| nearest_centroid(p, &centroids))
+            .collect::<MathResult<Vec<usize>>>()?;
+        if next == assignments {
+            break;
+        }
+        assignments = next;
+    }
+    
+    Ok(assignments)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -206,6 +1072,34 @@ mod tests {
         assert_eq!(mode(&no_mode), Some(1.0)); // Returns first in case of tie
     }
     
+    #[test]
+    fn test_mode_with_count() {
+        let data = [4.5, 1.0, 4.5, 2.0, 3.0, 4.5, 2.0];
+        assert_eq!(mode_with_count(&data).unwrap(), (vec![4.5], 3));
+        
+        let tied = [3.0, 1.0, 3.0, 1.0, 2.0];
+        assert_eq!(mode_with_count(&tied).unwrap(), (vec![3.0, 1.0], 2));
+        
+        let all_unique = [1.0, 2.0];
+        assert_eq!(mode_with_count(&all_unique).unwrap(), (vec![1.0, 2.0], 1));
+        
+        assert!(matches!(mode_with_count(&[]), Err(MathError::EmptyDataSet)));
+    }
+    
+    #[test]
+    fn test_mode_with_count_special_values() {
+        // Signed zeros are one value
+        let zeros = [0.0, -0.0, 1.0];
+        assert_eq!(mode_with_count(&zeros).unwrap(), (vec![0.0], 2));
+        
+        // All NaNs are grouped together
+        let nans = [f64::NAN, 1.0, -f64::NAN, f64::NAN];
+        let (values, count) = mode_with_count(&nans).unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(values.len(), 1);
+        assert!(values[0].is_nan());
+    }
+    
     #[test]
     fn test_variance() {
         let numbers = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
@@ -224,4 +1118,304 @@ mod tests {
         let y = vec![2.0, 4.0, 5.0, 4.0, 5.0];
         assert_relative_eq!(correlation(&x, &y).unwrap(), 0.8366, epsilon = 1e-4);
     }
+    
+    #[test]
+    fn test_histogram_json() {
+        let data = vec![1.0, 2.0, 2.0, 3.0, 5.0, 8.0, 9.0];
+        let json = histogram_json(&data, 4).unwrap();
+        
+        let bins: Vec<HistogramBin> = serde_json::from_str(&json).unwrap();
+        assert_eq!(bins.len(), 4);
+        assert_eq!(bins.iter().map(|b| b.count).sum::<usize>(), data.len());
+        assert_relative_eq!(bins[0].start, 1.0);
+        assert_relative_eq!(bins[3].end, 9.0);
+        
+        assert!(matches!(histogram_json(&[], 4), Err(MathError::EmptyDataSet)));
+        assert!(matches!(histogram_json(&data, 0), Err(MathError::InvalidInput(_))));
+    }
+    
+    #[test]
+    fn test_windows_map() {
+        let data = vec![4.0, 1.0, 3.0, 2.0, 6.0, 5.0];
+        let rolling_sum = windows_map(&data, 3, |w| w.iter().sum::<f64>()).unwrap();
+        
+        let mut expected = Vec::new();
+        for i in 0..=data.len() - 3 {
+            expected.push(data[i] + data[i + 1] + data[i + 2]);
+        }
+        assert_eq!(rolling_sum, expected);
+        
+        assert!(windows_map(&data, 0, |w| w.len()).is_err());
+        assert!(windows_map(&data, 7, |w| w.len()).is_err());
+    }
+    
+    #[test]
+    fn test_min_max_range() {
+        let numbers = vec![-3.5, 2.0, -7.25, 4.0, 0.0];
+        assert_relative_eq!(min(&numbers).unwrap(), -7.25);
+        assert_relative_eq!(max(&numbers).unwrap(), 4.0);
+        assert_relative_eq!(range(&numbers).unwrap(), 11.25);
+        
+        assert!(matches!(min(&[]), Err(MathError::EmptyDataSet)));
+        assert!(matches!(range(&[]), Err(MathError::EmptyDataSet)));
+        
+        let with_nan = vec![1.0, f64::NAN, 3.0];
+        assert!(matches!(min(&with_nan), Err(MathError::InvalidInput(_))));
+        assert!(matches!(max(&with_nan), Err(MathError::InvalidInput(_))));
+    }
+    
+    #[test]
+    fn test_geometric_mean() {
+        assert_relative_eq!(geometric_mean(&[1.0, 4.0]).unwrap(), 2.0, epsilon = 1e-10);
+        assert_relative_eq!(geometric_mean(&[2.0, 8.0, 4.0]).unwrap(), 4.0, epsilon = 1e-10);
+        
+        assert!(matches!(geometric_mean(&[]), Err(MathError::EmptyDataSet)));
+        assert!(matches!(geometric_mean(&[1.0, 0.0]), Err(MathError::InvalidInput(_))));
+        assert!(matches!(geometric_mean(&[1.0, -2.0]), Err(MathError::InvalidInput(_))));
+    }
+    
+    #[test]
+    fn test_harmonic_mean() {
+        assert_relative_eq!(harmonic_mean(&[1.0, 4.0, 4.0]).unwrap(), 2.0, epsilon = 1e-10);
+        assert_relative_eq!(harmonic_mean(&[40.0, 60.0]).unwrap(), 48.0, epsilon = 1e-10);
+        
+        assert!(matches!(harmonic_mean(&[]), Err(MathError::EmptyDataSet)));
+        assert!(matches!(harmonic_mean(&[1.0, 0.0]), Err(MathError::DivisionByZero)));
+    }
+    
+    #[test]
+    fn test_moving_average() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(moving_average(&data, 3).unwrap(), vec![2.0, 3.0, 4.0]);
+        assert_eq!(moving_average(&data, 1).unwrap(), data);
+        assert_eq!(moving_average(&data, 5).unwrap(), vec![3.0]);
+        
+        assert!(matches!(moving_average(&data, 0), Err(MathError::InvalidInput(_))));
+        assert!(matches!(moving_average(&data, 6), Err(MathError::InvalidInput(_))));
+    }
+    
+    #[test]
+    fn test_ema() {
+        let data = vec![10.0, 20.0, 10.0, 30.0];
+        // s0 = 10, s1 = 0.5*20 + 0.5*10 = 15, s2 = 0.5*10 + 0.5*15 = 12.5,
+        // s3 = 0.5*30 + 0.5*12.5 = 21.25
+        let smoothed = ema(&data, 0.5).unwrap();
+        assert_eq!(smoothed.len(), data.len());
+        assert_relative_eq!(smoothed[0], 10.0);
+        assert_relative_eq!(smoothed[1], 15.0);
+        assert_relative_eq!(smoothed[2], 12.5);
+        assert_relative_eq!(smoothed[3], 21.25);
+        
+        // alpha = 1 tracks the input exactly
+        assert_eq!(ema(&data, 1.0).unwrap(), data);
+        
+        assert!(matches!(ema(&[], 0.5), Err(MathError::EmptyDataSet)));
+        assert!(matches!(ema(&data, 0.0), Err(MathError::OutOfRange(_))));
+        assert!(matches!(ema(&data, 1.5), Err(MathError::OutOfRange(_))));
+        assert!(matches!(ema(&data, f64::NAN), Err(MathError::OutOfRange(_))));
+    }
+    
+    #[test]
+    fn test_quartiles() {
+        let (q1, q2, q3) = quartiles(&[7.0, 1.0, 3.0, 5.0]).unwrap();
+        assert_relative_eq!(q1, 2.5);
+        assert_relative_eq!(q2, 4.0);
+        assert_relative_eq!(q3, 5.5);
+        
+        assert_eq!(quartiles(&[4.0]).unwrap(), (4.0, 4.0, 4.0));
+        assert!(matches!(quartiles(&[]), Err(MathError::EmptyDataSet)));
+    }
+    
+    #[test]
+    fn test_outliers() {
+        let data = vec![3.0, 5.0, 4.0, 1000.0, 6.0, 5.0, -400.0, 4.0];
+        assert_eq!(outliers(&data).unwrap(), vec![1000.0, -400.0]);
+        assert_eq!(
+            remove_outliers(&data).unwrap(),
+            vec![3.0, 5.0, 4.0, 6.0, 5.0, 4.0]
+        );
+        
+        let clean = vec![1.0, 2.0, 3.0, 4.0];
+        assert!(outliers(&clean).unwrap().is_empty());
+        assert_eq!(remove_outliers(&clean).unwrap(), clean);
+        
+        assert!(matches!(outliers(&[]), Err(MathError::EmptyDataSet)));
+        assert!(matches!(remove_outliers(&[]), Err(MathError::EmptyDataSet)));
+    }
+    
+    #[test]
+    fn test_z_scores() {
+        let data = vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let scores = z_scores(&data).unwrap();
+        
+        assert_eq!(scores.len(), data.len());
+        assert_relative_eq!(mean(&scores).unwrap(), 0.0, epsilon = 1e-12);
+        
+        // Sample standard deviation of the scores is one
+        let sum_squares: f64 = scores.iter().map(|z| z * z).sum();
+        assert_relative_eq!(sum_squares / (scores.len() - 1) as f64, 1.0, epsilon = 1e-12);
+        
+        assert!(matches!(z_scores(&[]), Err(MathError::EmptyDataSet)));
+        assert!(matches!(z_scores(&[3.0, 3.0, 3.0]), Err(MathError::DivisionByZero)));
+        assert!(matches!(z_scores(&[3.0]), Err(MathError::DivisionByZero)));
+    }
+    
+    #[test]
+    fn test_min_max_normalize() {
+        let scaled = min_max_normalize(&[-5.0, 0.0, 15.0]).unwrap();
+        assert_eq!(scaled, vec![0.0, 0.25, 1.0]);
+        
+        assert!(matches!(min_max_normalize(&[]), Err(MathError::EmptyDataSet)));
+        assert!(matches!(min_max_normalize(&[2.0, 2.0]), Err(MathError::DivisionByZero)));
+    }
+    
+    #[test]
+    fn test_kahan_sum_preserves_small_values() {
+        let mut data = vec![1.0];
+        data.extend(std::iter::repeat_n(1e-16, 10_000));
+        
+        // Each 1e-16 is below half an ulp of 1.0, so naive summation drops them all
+        let naive: f64 = data.iter().sum();
+        assert_eq!(naive, 1.0);
+        
+        assert_relative_eq!(kahan_sum(&data), 1.0 + 1e-12, epsilon = 1e-15);
+        assert_eq!(kahan_sum(&[]), 0.0);
+    }
+    
+    #[test]
+    fn test_mean_stable() {
+        assert_relative_eq!(mean_stable(&[1.0, 2.0, 3.0, 4.0]).unwrap(), 2.5);
+        
+        let mut data = vec![1.0];
+        data.extend(std::iter::repeat_n(1e-16, 9_999));
+        let expected = (1.0 + 9_999.0 * 1e-16) / 10_000.0;
+        assert_relative_eq!(mean_stable(&data).unwrap(), expected, max_relative = 1e-15);
+        
+        assert!(matches!(mean_stable(&[]), Err(MathError::EmptyDataSet)));
+    }
+    
+    #[test]
+    fn test_frequencies() {
+        let letters: Vec<char> = "mississippi".chars().collect();
+        let counts = frequencies(&letters);
+        
+        assert_eq!(counts.len(), 4);
+        assert_eq!(counts[&'s'], 4);
+        assert_eq!(counts[&'i'], 4);
+        assert_eq!(counts[&'p'], 2);
+        assert_eq!(counts[&'m'], 1);
+        assert!(frequencies::<char>(&[]).is_empty());
+    }
+    
+    #[test]
+    fn test_most_common() {
+        let letters: Vec<char> = "mississippi".chars().collect();
+        
+        // 'i' and 's' tie on 4; 'i' appears first
+        assert_eq!(most_common(&letters, 2), vec![('i', 4), ('s', 4)]);
+        assert_eq!(most_common(&letters, 10).len(), 4);
+        assert!(most_common(&letters, 0).is_empty());
+    }
+    
+    #[test]
+    fn test_trimmed_mean() {
+        let data = vec![1.0, 2.0, 3.0, 4.0, 5.0, 100.0];
+        assert_relative_eq!(trimmed_mean(&data, 0.2).unwrap(), 3.5);
+        assert_relative_eq!(trimmed_mean(&data, 0.0).unwrap(), 115.0 / 6.0);
+        
+        // 0.1 of six values rounds down to nothing trimmed
+        assert_relative_eq!(trimmed_mean(&data, 0.1).unwrap(), 115.0 / 6.0);
+        assert_relative_eq!(trimmed_mean(&[7.0], 0.49).unwrap(), 7.0);
+        
+        assert!(matches!(trimmed_mean(&data, 0.5), Err(MathError::OutOfRange(_))));
+        assert!(matches!(trimmed_mean(&data, -0.1), Err(MathError::OutOfRange(_))));
+        assert!(matches!(trimmed_mean(&[], 0.2), Err(MathError::EmptyDataSet)));
+    }
+    
+    #[test]
+    fn test_skewness() {
+        assert_relative_eq!(skewness(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap(), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(skewness(&[-3.0, -1.0, 0.0, 1.0, 3.0]).unwrap(), 0.0, epsilon = 1e-12);
+        
+        // m2 = 1.6875 and m3 = 2.53125, giving 2 / sqrt(3)
+        let right_tailed = skewness(&[1.0, 1.0, 1.0, 4.0]).unwrap();
+        assert_relative_eq!(right_tailed, 2.0 / 3.0_f64.sqrt(), epsilon = 1e-12);
+        assert_relative_eq!(skewness(&[-1.0, -1.0, -1.0, -4.0]).unwrap(), -right_tailed);
+        
+        assert!(matches!(skewness(&[]), Err(MathError::EmptyDataSet)));
+        assert!(matches!(skewness(&[1.0, 2.0]), Err(MathError::InvalidInput(_))));
+        assert!(matches!(skewness(&[2.0, 2.0, 2.0]), Err(MathError::DivisionByZero)));
+    }
+    
+    #[test]
+    fn test_kurtosis() {
+        assert_relative_eq!(kurtosis(&[0.0, 1.0, 0.0, 1.0]).unwrap(), -2.0, epsilon = 1e-12);
+        // Uniform 1..=5: m2 = 2, m4 = 6.8
+        assert_relative_eq!(kurtosis(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap(), 6.8 / 4.0 - 3.0, epsilon = 1e-12);
+        
+        assert!(matches!(kurtosis(&[1.0, 2.0, 3.0]), Err(MathError::InvalidInput(_))));
+        assert!(matches!(kurtosis(&[5.0; 4]), Err(MathError::DivisionByZero)));
+    }
+    
+    #[test]
+    fn test_windows_owned() {
+        let windows = windows_owned(&[1, 2, 3, 4], 2).unwrap();
+        assert_eq!(windows, vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+        assert_eq!(windows_owned(&["a", "b"], 2).unwrap(), vec![vec!["a", "b"]]);
+        
+        assert!(matches!(windows_owned(&[1, 2], 0), Err(MathError::InvalidInput(_))));
+        assert!(matches!(windows_owned(&[1, 2], 3), Err(MathError::InvalidInput(_))));
+    }
+    
+    #[test]
+    fn test_windows_iter_is_lazy() {
+        let data: Vec<u32> = (0..1_000_000).collect();
+        let first_large = windows_iter(&data, 3)
+            .unwrap()
+            .find(|w| w.iter().sum::<u32>() > 30)
+            .unwrap();
+        assert_eq!(first_large, &[10, 11, 12]);
+        
+        assert!(windows_iter::<u32>(&[], 1).is_err());
+    }
+    
+    #[test]
+    fn test_kmeans_two_clusters() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        
+        let coords = [
+            [0.0, 0.0], [0.3, 0.1], [0.1, 0.4], [-0.2, 0.2],
+            [9.0, 9.0], [9.4, 8.8], [8.7, 9.3], [9.1, 9.2],
+        ];
+        let points: Vec<VecN<f64>> = coords.iter().map(|c| VecN::new(c.to_vec())).collect();
+        
+        for seed in 0..10 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let labels = kmeans(&points, 2, 100, &mut rng).unwrap();
+            assert!(labels[..4].iter().all(|&l| l == labels[0]));
+            assert!(labels[4..].iter().all(|&l| l == labels[4]));
+            assert_ne!(labels[0], labels[4]);
+        }
+        
+        // One cluster per point
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut labels = kmeans(&points, points.len(), 10, &mut rng).unwrap();
+        labels.sort();
+        assert_eq!(labels, (0..points.len()).collect::<Vec<_>>());
+    }
+    
+    #[test]
+    fn test_kmeans_invalid_input() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        
+        let mut rng = StdRng::seed_from_u64(0);
+        let points = vec![VecN::new(vec![0.0, 0.0]), VecN::new(vec![1.0, 1.0])];
+        assert!(matches!(kmeans(&points, 0, 10, &mut rng), Err(MathError::InvalidInput(_))));
+        assert!(matches!(kmeans(&points, 3, 10, &mut rng), Err(MathError::InvalidInput(_))));
+        
+        let ragged = vec![VecN::new(vec![0.0, 0.0]), VecN::new(vec![1.0])];
+        assert!(matches!(kmeans(&ragged, 1, 10, &mut rng), Err(MathError::InvalidInput(_))));
+    }
 } 
\ No newline at end of file
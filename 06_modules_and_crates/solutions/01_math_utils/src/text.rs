@@ -0,0 +1,397 @@
+//! Text module
+//!
+//! This module provides small text algorithms that work on `char`s rather
+//! than bytes, so multibyte characters are handled correctly.
+//!
+//! # Examples
+//!
+//! ```
+//! use math_utils::text::{rle_decode, rle_encode};
+//!
+//! let encoded = rle_encode("aaab").unwrap();
+//! assert_eq!(encoded, "a3b1");
+//! assert_eq!(rle_decode(&encoded).unwrap(), "aaab");
+//! ```
+
+use crate::{MathError, MathResult};
+
+/// Largest output, in bytes, that [`rle_decode`] will produce (1 GiB)
+pub const RLE_MAX_DECODED_LEN: usize = 1 << 30;
+
+/// Run-length encodes a string as each character followed by its repeat count
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::text::rle_encode;
+/// assert_eq!(rle_encode("aaab").unwrap(), "a3b1");
+/// assert_eq!(rle_encode("ééé").unwrap(), "é3");
+/// assert_eq!(rle_encode("").unwrap(), "");
+/// assert!(rle_encode("a1").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if the input contains an ASCII digit,
+/// since [`rle_decode`] would read it back as part of a count
+pub fn rle_encode(input: &str) -> MathResult<String> {
+    if let Some(d) = input.chars().find(|c| c.is_ascii_digit()) {
+        return Err(MathError::InvalidInput(format!("cannot encode digit '{}'", d)));
+    }
+    
+    let mut encoded = String::new();
+    let mut chars = input.chars().peekable();
+    
+    while let Some(c) = chars.next() {
+        let mut count = 1;
+        while chars.next_if_eq(&c).is_some() {
+            count += 1;
+        }
+        encoded.push(c);
+        encoded.push_str(&count.to_string());
+    }
+    
+    Ok(encoded)
+}
+
+/// Decodes a string produced by [`rle_encode`]
+///
+/// A short input can ask for an enormous output (`"a99999999999"` is about
+/// 100 GB), so the decoded length is checked against [`RLE_MAX_DECODED_LEN`]
+/// before anything is allocated.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::text::rle_decode;
+/// assert_eq!(rle_decode("a3b1").unwrap(), "aaab");
+/// assert_eq!(rle_decode("x12").unwrap(), "x".repeat(12));
+/// assert!(rle_decode("ab").is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns `MathError::InvalidInput` if the input starts with a digit, a
+/// character is not followed by a count, or a count is zero.
+/// Returns `MathError::OutOfRange` if a count does not fit in `usize` or the
+/// decoded string would exceed `RLE_MAX_DECODED_LEN` bytes
+pub fn rle_decode(input: &str) -> MathResult<String> {
+    let mut decoded = String::new();
+    let mut decoded_len: usize = 0;
+    let mut chars = input.chars().peekable();
+    
+    while let Some(c) = chars.next() {
+        if c.is_ascii_digit() {
+            return Err(MathError::InvalidInput(format!("expected a character, found digit '{}'", c)));
+        }
+        
+        let mut digits = String::new();
+        while let Some(d) = chars.next_if(|d| d.is_ascii_digit()) {
+            digits.push(d);
+        }
+        if digits.is_empty() {
+            return Err(MathError::InvalidInput(format!("missing count after '{}'", c)));
+        }
+        
+        let count: usize = digits
+            .parse()
+            .map_err(|_| MathError::OutOfRange(format!("count {} is too large", digits)))?;
+        if count == 0 {
+            return Err(MathError::InvalidInput(format!("zero count for '{}'", c)));
+        }
+        
+        decoded_len = count
+            .checked_mul(c.len_utf8())
+            .and_then(|bytes| bytes.checked_add(decoded_len))
+            .filter(|&total| total <= RLE_MAX_DECODED_LEN)
+            .ok_or_else(|| {
+                MathError::OutOfRange(format!(
+                    "decoded string would exceed {} bytes",
+                    RLE_MAX_DECODED_LEN
+                ))
+            })?;
+        decoded.extend(std::iter::repeat_n(c, count));
+    }
+    
+    Ok(decoded)
+}
+
+/// Shifts a single ASCII letter within its alphabet, preserving case
+fn shift_letter(c: char, shift: i32) -> char {
+    let base = if c.is_ascii_lowercase() { b'a' } else { b'A' };
+    let offset = (c as u8 - base) as i32;
+    // Reduce the shift first so large shifts cannot overflow
+    let shifted = (offset + shift.rem_euclid(26)) % 26;
+    (base + shifted as u8) as char
+}
+
+/// Encrypts text with a Caesar cipher, shifting each ASCII letter by `shift`
+///
+/// Shifts wrap around the alphabet and may be negative. Case is preserved and
+/// every other character is left unchanged. A shift of 13 is ROT13.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::text::caesar_encode;
+/// assert_eq!(caesar_encode("abc", 3), "def");
+/// assert_eq!(caesar_encode("Hello, World!", 13), "Uryyb, Jbeyq!");
+/// ```
+pub fn caesar_encode(text: &str, shift: i32) -> String {
+    text.chars()
+        .map(|c| if c.is_ascii_alphabetic() { shift_letter(c, shift) } else { c })
+        .collect()
+}
+
+/// Decrypts text produced by [`caesar_encode`] with the same `shift`
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::text::caesar_decode;
+/// assert_eq!(caesar_decode("def", 3), "abc");
+/// ```
+pub fn caesar_decode(text: &str, shift: i32) -> String {
+    // Reduce first so negating i32::MIN cannot overflow
+    caesar_encode(text, -shift.rem_euclid(26))
+}
+
+/// Calculates the Levenshtein edit distance between two strings
+///
+/// Counts the minimum number of single-character insertions, deletions and
+/// substitutions needed to turn `a` into `b`. Works on `char`s, so each
+/// multibyte character counts as one edit.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::text::levenshtein;
+/// assert_eq!(levenshtein("kitten", "sitting"), 3);
+/// assert_eq!(levenshtein("", "abc"), 3);
+/// ```
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    
+    // Only the previous row of the DP table is needed to fill the next one;
+    // row[j] is the distance between the current prefix of `a` and b[..j]
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            let deletion = previous[j + 1] + 1;
+            let insertion = current[j] + 1;
+            current[j + 1] = substitution.min(deletion).min(insertion);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    
+    previous[b.len()]
+}
+
+/// Finds the candidate with the smallest Levenshtein distance to `query`
+///
+/// Ties go to the candidate that appears first. Returns `None` if there are
+/// no candidates.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::text::closest_match;
+/// let titles = vec!["Dune".to_string(), "Emma".to_string(), "Ulysses".to_string()];
+/// assert_eq!(closest_match("Ulyses", &titles), Some(&titles[2]));
+/// ```
+pub fn closest_match<'a>(query: &str, candidates: &'a [String]) -> Option<&'a String> {
+    candidates.iter().min_by_key(|candidate| levenshtein(query, candidate))
+}
+
+/// Returns the Soundex digit of an uppercase ASCII letter, or `None` for
+/// letters without one (vowels, `H`, `W` and `Y`)
+fn soundex_digit(c: char) -> Option<char> {
+    match c {
+        'B' | 'F' | 'P' | 'V' => Some('1'),
+        'C' | 'G' | 'J' | 'K' | 'Q' | 'S' | 'X' | 'Z' => Some('2'),
+        'D' | 'T' => Some('3'),
+        'L' => Some('4'),
+        'M' | 'N' => Some('5'),
+        'R' => Some('6'),
+        _ => None,
+    }
+}
+
+/// Calculates the four-character American Soundex code of a name
+///
+/// Names that sound alike, such as "Smith" and "Smyth", share a code.
+/// Characters other than ASCII letters are ignored, and a name without any
+/// ASCII letters gives an empty string.
+///
+/// # Examples
+///
+/// ```
+/// use math_utils::text::soundex;
+/// assert_eq!(soundex("Robert"), "R163");
+/// assert_eq!(soundex("Rupert"), "R163");
+/// assert_eq!(soundex("Lee"), "L000");
+/// ```
+pub fn soundex(name: &str) -> String {
+    let mut letters = name
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| c.to_ascii_uppercase());
+    
+    let Some(first) = letters.next() else {
+        return String::new();
+    };
+    
+    let mut code = String::from(first);
+    let mut previous = soundex_digit(first);
+    for c in letters {
+        if code.len() == 4 {
+            break;
+        }
+        match c {
+            // H and W do not separate letters with the same digit
+            'H' | 'W' => {}
+            _ => {
+                let digit = soundex_digit(c);
+                if let Some(d) = digit {
+                    if digit != previous {
+                        code.push(d);
+                    }
+                }
+                previous = digit;
+            }
+        }
+    }
+    
+    while code.len() < 4 {
+        code.push('0');
+    }
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_rle_round_trip() {
+        for input in ["", "a", "aaab", "abc", "zzzzzzzzzzzz", "héééllo wörld", "🦀🦀🦀🙂"] {
+            assert_eq!(rle_decode(&rle_encode(input).unwrap()).unwrap(), input);
+        }
+    }
+    
+    #[test]
+    fn test_rle_multibyte_counts_chars() {
+        assert_eq!(rle_encode("ééé").unwrap(), "é3");
+        assert_eq!(rle_encode("🦀🦀a").unwrap(), "🦀2a1");
+        assert_eq!(rle_decode("ö2").unwrap(), "öö");
+    }
+    
+    #[test]
+    fn test_rle_encode_rejects_digits() {
+        assert!(matches!(rle_encode("a1"), Err(MathError::InvalidInput(_))));
+        assert!(matches!(rle_encode("room 101"), Err(MathError::InvalidInput(_))));
+        // Non-ASCII digits are not read as counts, so they are fine
+        assert_eq!(rle_encode("٣٣").unwrap(), "٣2");
+    }
+    
+    #[test]
+    fn test_rle_decode_malformed() {
+        assert!(matches!(rle_decode("3a"), Err(MathError::InvalidInput(_))));
+        assert!(matches!(rle_decode("a2b"), Err(MathError::InvalidInput(_))));
+        assert!(matches!(rle_decode("a0"), Err(MathError::InvalidInput(_))));
+        assert!(matches!(
+            rle_decode("a99999999999999999999999"),
+            Err(MathError::OutOfRange(_))
+        ));
+    }
+    
+    #[test]
+    fn test_rle_decode_length_cap() {
+        assert!(matches!(rle_decode("a18446744073709551615"), Err(MathError::OutOfRange(_))));
+        assert!(matches!(rle_decode("a99999999999"), Err(MathError::OutOfRange(_))));
+        // The cap counts bytes, so a two-byte character reaches it in half as many repeats
+        assert!(matches!(
+            rle_decode(&format!("é{}", RLE_MAX_DECODED_LEN / 2 + 1)),
+            Err(MathError::OutOfRange(_))
+        ));
+    }
+    
+    #[test]
+    fn test_caesar_shift() {
+        assert_eq!(caesar_encode("abc", 3), "def");
+        assert_eq!(caesar_encode("xyz", 3), "abc");
+        assert_eq!(caesar_encode("ABC", -1), "ZAB");
+        assert_eq!(caesar_encode("abc", 29), "def");
+        assert_eq!(caesar_encode("Café 42!", 1), "Dbgé 42!");
+    }
+    
+    #[test]
+    fn test_caesar_rot13_is_own_inverse() {
+        let text = "The Quick Brown Fox, jumps over 13 lazy dogs.";
+        let once = caesar_encode(text, 13);
+        assert_ne!(once, text);
+        assert_eq!(caesar_encode(&once, 13), text);
+        
+        for shift in [-27, -3, 0, 5, 26, i32::MIN, i32::MAX] {
+            assert_eq!(caesar_decode(&caesar_encode(text, shift), shift), text);
+        }
+    }
+    
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("sitting", "kitten"), 3);
+        assert_eq!(levenshtein("flaw", "lawn"), 2);
+        assert_eq!(levenshtein("same", "same"), 0);
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("abc", ""), 3);
+        // One substitution, not two bytes
+        assert_eq!(levenshtein("café", "cafe"), 1);
+    }
+    
+    #[test]
+    fn test_closest_match() {
+        let candidates: Vec<String> = ["hammer", "screwdriver", "wrench", "saw"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        
+        assert_eq!(closest_match("wrentch", &candidates), Some(&candidates[2]));
+        assert_eq!(closest_match("hamer", &candidates), Some(&candidates[0]));
+        assert_eq!(closest_match("sew", &candidates), Some(&candidates[3]));
+        assert_eq!(closest_match("anything", &[]), None);
+        
+        // Equal distances keep the first candidate
+        let tied = vec!["cat".to_string(), "bat".to_string()];
+        assert_eq!(closest_match("hat", &tied), Some(&tied[0]));
+    }
+    
+    #[test]
+    fn test_soundex() {
+        assert_eq!(soundex("Robert"), "R163");
+        assert_eq!(soundex("Rupert"), "R163");
+        assert_eq!(soundex("Smith"), soundex("Smyth"));
+        assert_eq!(soundex("Smith"), "S530");
+        // Letters with the same digit on either side of H or W are coded once
+        assert_eq!(soundex("Ashcraft"), "A261");
+        // ...but a vowel in between splits them
+        assert_eq!(soundex("Tymczak"), "T522");
+        // The first letter's digit also counts as the previous one
+        assert_eq!(soundex("Pfister"), "P236");
+        assert_eq!(soundex("Lee"), "L000");
+        assert_eq!(soundex("washington"), "W252");
+    }
+    
+    #[test]
+    fn test_soundex_non_ascii() {
+        assert_eq!(soundex("Müller"), "M460");
+        assert_eq!(soundex("  O'Brien "), "O165");
+        assert_eq!(soundex("张伟"), "");
+        assert_eq!(soundex(""), "");
+    }
+} 
\ No newline at end of file
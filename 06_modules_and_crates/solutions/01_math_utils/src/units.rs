@@ -0,0 +1,129 @@
+//! Units module
+//!
+//! This module provides temperature newtypes so values in different units
+//! cannot be mixed up, with conversions between them checked by the compiler.
+//!
+//! # Examples
+//!
+//! ```
+//! use math_utils::units::{Celsius, Fahrenheit, Kelvin};
+//!
+//! let boiling = Fahrenheit::from(Celsius(100.0));
+//! assert!((boiling.0 - 212.0).abs() < 1e-10);
+//!
+//! let kelvin = Kelvin::try_from(Celsius(-300.0));
+//! assert!(kelvin.is_err());
+//! ```
+
+use crate::{MathError, MathResult};
+
+/// Absolute zero expressed in degrees Celsius
+pub const ABSOLUTE_ZERO_CELSIUS: f64 = -273.15;
+
+/// A temperature in degrees Celsius
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Celsius(pub f64);
+
+/// A temperature in degrees Fahrenheit
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Fahrenheit(pub f64);
+
+/// A temperature in kelvin, guaranteed to be at or above absolute zero
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Kelvin(f64);
+
+impl Kelvin {
+    /// Creates a new kelvin temperature
+    ///
+    /// # Errors
+    ///
+    /// Returns `MathError::OutOfRange` if the value is below absolute zero
+    pub fn new(value: f64) -> MathResult<Self> {
+        if value < 0.0 || value.is_nan() {
+            Err(MathError::OutOfRange(format!("{} K is below absolute zero", value)))
+        } else {
+            Ok(Kelvin(value))
+        }
+    }
+    
+    /// Returns the temperature in kelvin
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+}
+
+impl From<Celsius> for Fahrenheit {
+    fn from(c: Celsius) -> Self {
+        Fahrenheit(c.0 * 9.0 / 5.0 + 32.0)
+    }
+}
+
+impl From<Fahrenheit> for Celsius {
+    fn from(f: Fahrenheit) -> Self {
+        Celsius((f.0 - 32.0) * 5.0 / 9.0)
+    }
+}
+
+impl From<Kelvin> for Celsius {
+    fn from(k: Kelvin) -> Self {
+        Celsius(k.0 + ABSOLUTE_ZERO_CELSIUS)
+    }
+}
+
+impl From<Kelvin> for Fahrenheit {
+    fn from(k: Kelvin) -> Self {
+        Fahrenheit::from(Celsius::from(k))
+    }
+}
+
+impl TryFrom<Celsius> for Kelvin {
+    type Error = MathError;
+    
+    fn try_from(c: Celsius) -> MathResult<Self> {
+        Kelvin::new(c.0 - ABSOLUTE_ZERO_CELSIUS)
+    }
+}
+
+impl TryFrom<Fahrenheit> for Kelvin {
+    type Error = MathError;
+    
+    fn try_from(f: Fahrenheit) -> MathResult<Self> {
+        Kelvin::try_from(Celsius::from(f))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    
+    #[test]
+    fn test_celsius_fahrenheit_round_trip() {
+        assert_relative_eq!(Fahrenheit::from(Celsius(0.0)).0, 32.0);
+        assert_relative_eq!(Fahrenheit::from(Celsius(-40.0)).0, -40.0);
+        assert_relative_eq!(Celsius::from(Fahrenheit(212.0)).0, 100.0);
+        
+        let original = Celsius(36.6);
+        let round_trip = Celsius::from(Fahrenheit::from(original));
+        assert_relative_eq!(round_trip.0, original.0, epsilon = 1e-10);
+    }
+    
+    #[test]
+    fn test_kelvin_round_trip() {
+        let kelvin = Kelvin::try_from(Celsius(25.0)).unwrap();
+        assert_relative_eq!(kelvin.value(), 298.15);
+        assert_relative_eq!(Celsius::from(kelvin).0, 25.0, epsilon = 1e-10);
+        
+        let kelvin = Kelvin::try_from(Fahrenheit(32.0)).unwrap();
+        assert_relative_eq!(kelvin.value(), 273.15, epsilon = 1e-10);
+        assert_relative_eq!(Fahrenheit::from(kelvin).0, 32.0, epsilon = 1e-10);
+    }
+    
+    #[test]
+    fn test_below_absolute_zero() {
+        assert!(Kelvin::new(0.0).is_ok());
+        assert!(matches!(Kelvin::new(-1.0), Err(MathError::OutOfRange(_))));
+        assert!(matches!(Kelvin::try_from(Celsius(-274.0)), Err(MathError::OutOfRange(_))));
+        assert!(matches!(Kelvin::try_from(Fahrenheit(-500.0)), Err(MathError::OutOfRange(_))));
+    }
+} 
\ No newline at end of file
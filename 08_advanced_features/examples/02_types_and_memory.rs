@@ -108,6 +108,43 @@ impl CustomVec {
             capacity,
         }
     }
+    
+    // Appends a value; panics if the allocation is already full
+    fn push(&mut self, value: u32) {
+        assert!(self.len < self.capacity, "CustomVec is full");
+        unsafe {
+            self.ptr.add(self.len).write(value);
+        }
+        self.len += 1;
+    }
+    
+    // Views only the initialized prefix, never the spare capacity
+    fn as_slice(&self) -> &[u32] {
+        if self.len == 0 {
+            // `ptr` may be null, which from_raw_parts never allows
+            &[]
+        } else {
+            unsafe { std::slice::from_raw_parts(self.ptr, self.len) }
+        }
+    }
+    
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    
+    // Removes all elements but keeps the allocation for reuse. `u32` has no
+    // destructor; a generic version would call `ptr::drop_in_place` on the
+    // initialized prefix before resetting `len`.
+    fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+// Compares lengths first, then the initialized elements only
+impl PartialEq for CustomVec {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.as_slice() == other.as_slice()
+    }
 }
 
 impl Drop for CustomVec {
@@ -151,6 +188,11 @@ fn main() {
     unsafe {
         let mut vec = CustomVec::with_capacity(5);
         println!("CustomVec capacity: {}", vec.capacity);
+        vec.push(10);
+        vec.push(20);
+        println!("CustomVec contents: {:?}", vec.as_slice());
+        vec.clear();
+        println!("After clear: empty = {}, capacity = {}", vec.is_empty(), vec.capacity);
         // vec is automatically deallocated when it goes out of scope
     }
     
@@ -194,6 +236,49 @@ mod tests {
         }
     }
     
+    #[test]
+    fn test_custom_vec_equality() {
+        unsafe {
+            let mut a = CustomVec::with_capacity(4);
+            let mut b = CustomVec::with_capacity(8);
+            a.push(1);
+            a.push(2);
+            b.push(1);
+            b.push(2);
+            // Capacity is not part of equality
+            assert!(a == b);
+            
+            b.push(3);
+            assert!(a != b);
+            
+            let mut c = CustomVec::with_capacity(2);
+            c.push(1);
+            c.push(9);
+            assert!(a != c);
+        }
+        assert!(CustomVec::new() == CustomVec::new());
+    }
+    
+    #[test]
+    fn test_custom_vec_clear() {
+        unsafe {
+            let mut vec = CustomVec::with_capacity(3);
+            assert!(vec.is_empty());
+            vec.push(7);
+            vec.push(8);
+            assert!(!vec.is_empty());
+            
+            vec.clear();
+            assert!(vec.is_empty());
+            assert_eq!(vec.len, 0);
+            assert_eq!(vec.capacity, 3);
+            
+            // The retained allocation can be reused
+            vec.push(5);
+            assert_eq!(vec.as_slice(), &[5]);
+        }
+    }
+    
     #[test]
     fn test_phantom_types() {
         let admin_token = Token::<AdminPrivileges>::new("admin123".to_string());
@@ -0,0 +1,172 @@
+// Example: Observer Pattern with Boxed Closures
+// This example demonstrates a reusable publish/subscribe building block using trait objects
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+// =============== Observable ===============
+
+// Holds any number of subscribers and calls each of them, in the order they
+// subscribed, whenever an event is fired
+type Subscriber<E> = Box<dyn FnMut(&E)>;
+
+struct Observable<E> {
+    subscribers: Vec<Subscriber<E>>,
+}
+
+impl<E> Observable<E> {
+    fn new() -> Self {
+        Observable {
+            subscribers: Vec::new(),
+        }
+    }
+    
+    fn subscribe(&mut self, f: Subscriber<E>) {
+        self.subscribers.push(f);
+    }
+    
+    fn notify(&mut self, event: &E) {
+        for subscriber in self.subscribers.iter_mut() {
+            subscriber(event);
+        }
+    }
+    
+    fn subscriber_count(&self) -> usize {
+        self.subscribers.len()
+    }
+}
+
+// =============== Example Domain ===============
+
+#[derive(Debug)]
+enum MachineEvent {
+    Sale { item: String, price: u32 },
+    OutOfStock(String),
+}
+
+struct VendingMachine {
+    stock: Vec<(String, u32, u32)>,
+    events: Observable<MachineEvent>,
+}
+
+impl VendingMachine {
+    fn new(stock: Vec<(String, u32, u32)>) -> Self {
+        VendingMachine {
+            stock,
+            events: Observable::new(),
+        }
+    }
+    
+    fn buy(&mut self, name: &str) {
+        if let Some((item, price, count)) = self.stock.iter_mut().find(|(n, _, _)| n == name) {
+            if *count == 0 {
+                self.events.notify(&MachineEvent::OutOfStock(item.clone()));
+                return;
+            }
+            *count -= 1;
+            let event = MachineEvent::Sale {
+                item: item.clone(),
+                price: *price,
+            };
+            self.events.notify(&event);
+        }
+    }
+}
+
+fn main() {
+    let mut machine = VendingMachine::new(vec![
+        ("Soda".to_string(), 150, 2),
+        ("Chips".to_string(), 100, 0),
+    ]);
+    
+    // Subscriber 1: log every event
+    machine.events.subscribe(Box::new(|event| match event {
+        MachineEvent::Sale { item, price } => println!("  [log] sold {} for {} cents", item, price),
+        MachineEvent::OutOfStock(item) => println!("  [log] {} is out of stock", item),
+    }));
+    
+    // Subscriber 2: keep a running revenue total in shared state
+    let revenue = Rc::new(Cell::new(0));
+    let revenue_handle = Rc::clone(&revenue);
+    machine.events.subscribe(Box::new(move |event| {
+        if let MachineEvent::Sale { price, .. } = event {
+            revenue_handle.set(revenue_handle.get() + price);
+        }
+    }));
+    
+    // Subscriber 3: collect restock requests
+    let restock = Rc::new(RefCell::new(Vec::new()));
+    let restock_handle = Rc::clone(&restock);
+    machine.events.subscribe(Box::new(move |event| {
+        if let MachineEvent::OutOfStock(item) = event {
+            restock_handle.borrow_mut().push(item.clone());
+        }
+    }));
+    
+    println!("Vending machine with {} subscribers:", machine.events.subscriber_count());
+    for item in ["Soda", "Chips", "Soda", "Soda"] {
+        machine.buy(item);
+    }
+    
+    println!("\nRevenue: {} cents", revenue.get());
+    println!("Needs restock: {:?}", restock.borrow());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_all_subscribers_receive_event() {
+        let mut observable = Observable::new();
+        let first = Rc::new(RefCell::new(Vec::new()));
+        let second = Rc::new(RefCell::new(Vec::new()));
+        
+        let first_handle = Rc::clone(&first);
+        observable.subscribe(Box::new(move |e: &i32| first_handle.borrow_mut().push(*e)));
+        let second_handle = Rc::clone(&second);
+        observable.subscribe(Box::new(move |e: &i32| second_handle.borrow_mut().push(*e * 10)));
+        
+        observable.notify(&1);
+        observable.notify(&2);
+        
+        assert_eq!(*first.borrow(), vec![1, 2]);
+        assert_eq!(*second.borrow(), vec![10, 20]);
+    }
+    
+    #[test]
+    fn test_counter_increments_per_event() {
+        let mut observable = Observable::new();
+        let counter = Rc::new(Cell::new(0));
+        let counter_handle = Rc::clone(&counter);
+        observable.subscribe(Box::new(move |_: &&str| counter_handle.set(counter_handle.get() + 1)));
+        
+        for event in ["a", "b", "c"] {
+            observable.notify(&event);
+        }
+        assert_eq!(counter.get(), 3);
+    }
+    
+    #[test]
+    fn test_notify_without_subscribers() {
+        let mut observable: Observable<String> = Observable::new();
+        observable.notify(&"nobody listening".to_string());
+        assert_eq!(observable.subscriber_count(), 0);
+    }
+    
+    #[test]
+    fn test_vending_machine_events() {
+        let mut machine = VendingMachine::new(vec![("Soda".to_string(), 150, 1)]);
+        let revenue = Rc::new(Cell::new(0));
+        let revenue_handle = Rc::clone(&revenue);
+        machine.events.subscribe(Box::new(move |event| {
+            if let MachineEvent::Sale { price, .. } = event {
+                revenue_handle.set(revenue_handle.get() + price);
+            }
+        }));
+        
+        machine.buy("Soda");
+        machine.buy("Soda");
+        assert_eq!(revenue.get(), 150);
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,130 @@
+// Example: Throttling with an Injectable Clock
+// This example demonstrates limiting how often an action fires, with time passed in as a closure
+
+use std::cell::Cell;
+use std::rc::Rc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// =============== Throttle ===============
+
+type Clock = Box<dyn Fn() -> Instant>;
+
+// Lets an action through at most once per `min_interval`. Calls in between are
+// dropped rather than queued, which suits redraws: only the latest state matters.
+struct Throttle {
+    min_interval: Duration,
+    last_fired: Option<Instant>,
+    now: Clock,
+}
+
+impl Throttle {
+    fn new(min_interval: Duration) -> Self {
+        Self::with_clock(min_interval, Box::new(Instant::now))
+    }
+    
+    // Reads the time from `now` instead of the system clock, so tests can
+    // control it
+    fn with_clock(min_interval: Duration, now: Clock) -> Self {
+        Throttle {
+            min_interval,
+            last_fired: None,
+            now,
+        }
+    }
+    
+    // True on the first call and whenever at least `min_interval` has passed
+    // since the last call that returned true
+    fn should_fire(&mut self) -> bool {
+        let now = (self.now)();
+        let ready = self
+            .last_fired
+            .is_none_or(|last| now.saturating_duration_since(last) >= self.min_interval);
+        if ready {
+            self.last_fired = Some(now);
+        }
+        ready
+    }
+}
+
+fn main() {
+    // A watch loop receiving updates every 20ms but redrawing at most every 100ms
+    println!("Updates every 20ms, redraws throttled to 100ms:");
+    let mut throttle = Throttle::new(Duration::from_millis(100));
+    let start = Instant::now();
+    let mut redraws = 0;
+    for update in 1..=15 {
+        if throttle.should_fire() {
+            redraws += 1;
+            println!("  update {:>2}: redraw at {:?}", update, start.elapsed());
+        }
+        thread::sleep(Duration::from_millis(20));
+    }
+    println!("  {} redraws for 15 updates", redraws);
+    
+    // The same throttle driven by a fake clock
+    println!("\nWith a manual clock:");
+    let base = Instant::now();
+    let offset_ms = Rc::new(Cell::new(0));
+    let clock_offset = Rc::clone(&offset_ms);
+    let mut throttle = Throttle::with_clock(
+        Duration::from_secs(1),
+        Box::new(move || base + Duration::from_millis(clock_offset.get())),
+    );
+    for ms in [0, 400, 999, 1000, 1500, 2100] {
+        offset_ms.set(ms);
+        println!("  t = {:>4}ms: fire = {}", ms, throttle.should_fire());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    // A throttle whose clock reads from the returned cell, in milliseconds
+    fn manual_throttle(min_interval_ms: u64) -> (Throttle, Rc<Cell<u64>>) {
+        let base = Instant::now();
+        let offset_ms = Rc::new(Cell::new(0));
+        let clock_offset = Rc::clone(&offset_ms);
+        let throttle = Throttle::with_clock(
+            Duration::from_millis(min_interval_ms),
+            Box::new(move || base + Duration::from_millis(clock_offset.get())),
+        );
+        (throttle, offset_ms)
+    }
+    
+    #[test]
+    fn test_only_spaced_out_calls_fire() {
+        let (mut throttle, clock) = manual_throttle(100);
+        
+        let mut fired = Vec::new();
+        for ms in [0, 10, 50, 99, 100, 150, 199, 250, 260, 400] {
+            clock.set(ms);
+            if throttle.should_fire() {
+                fired.push(ms);
+            }
+        }
+        assert_eq!(fired, vec![0, 100, 250, 400]);
+    }
+    
+    #[test]
+    fn test_dropped_calls_do_not_reset_interval() {
+        let (mut throttle, clock) = manual_throttle(100);
+        assert!(throttle.should_fire());
+        
+        // Rapid calls in between must not push the next allowed time back
+        for ms in (5..100).step_by(5) {
+            clock.set(ms);
+            assert!(!throttle.should_fire());
+        }
+        clock.set(100);
+        assert!(throttle.should_fire());
+    }
+    
+    #[test]
+    fn test_zero_interval_always_fires() {
+        let (mut throttle, _clock) = manual_throttle(0);
+        assert!(throttle.should_fire());
+        assert!(throttle.should_fire());
+    }
+} 
\ No newline at end of file